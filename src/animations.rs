@@ -7,25 +7,22 @@ use crate::*;
 /// fn init_animation(
 ///     mut animations: ResMut<Animations>,
 ///     mut commands: Commands,
-///     asset_server: ResMut<AssetServer>,
-///     mut texture_atlases: ResMut<Assets<TextureAtlas>>,
+///     asset_server: Res<AssetServer>,
+///     mut layouts: ResMut<Assets<TextureAtlasLayout>>,
 /// ) {
-///     let asset = asset_server.load("path/to/your/sprite_sheet");
+///     let image = asset_server.load("path/to/your/sprite_sheet.png");
 ///
-///     let texture_atlas = TextureAtlas::from_grid(asset, Vec2::new(16.0, 16.0), 10, 1, None, None);
-///
-///     let texture_atlas_handle = texture_atlases.add(texture_atlas);
+///     let layout = layouts.add(TextureAtlasLayout::from_grid(UVec2::new(16, 16), 10, 1, None, None));
 ///
 ///     let entity = commands
-///         .spawn(AnimationDirection::default())
-///         .insert(SpriteSheetBundle {
-///             texture_atlas: texture_atlas_handle.clone(),
-///             ..Default::default()
-///         })
+///         .spawn((
+///             Animator::default(),
+///             Sprite::from_atlas_image(image.clone(), TextureAtlas::from(layout.clone())),
+///         ))
 ///         .id();
 ///     animations.insert_animation(
 ///         NewAnimation {
-///             handle: texture_atlas_handle.clone(), /* the handle for the TextureAtlas */
+///             handles: Handles::new(image, layout), /* the sprite sheet and its layout */
 ///             animation: AnimationType::Timed(
 ///                 TimedAnimation::new(
 ///                     Vec::from(PLAYER_RUNNING_FRAMES),  /* animation_frames */
@@ -36,7 +33,7 @@ use crate::*;
 ///                         left_direction_is_flipped: true,
 ///                         x_direction_index: 3,
 ///                     }),
-///                     true,  /* repeating */
+///                     LoopMode::Loop, /* loop_mode */
 ///                     false, /* blocking */
 ///                     0,     /* blocking_priory */
 ///                 ),
@@ -49,152 +46,359 @@ use crate::*;
 /// ```
 #[derive(Clone, Debug, Default)]
 pub struct TimedAnimation {
-    animation_tick: usize,
-    previous_dir_index: usize,
     pub frame_timings_in_secs: Vec<f32>,
+    /// When not empty the frames are timed in ticks of `Time<Fixed>` instead of `frame_timings_in_secs`
+    pub frame_timings_in_ticks: Vec<u32>,
+    /// The clock the frames are timed with. [None] uses the one set on the [AnimationsPlugin]
+    pub time_source: Option<AnimationTimeSource>,
     pub blocking: bool,
     pub blocking_priority: i32,
     pub animation_frames: Vec<usize>,
-    pub frame: Vec2,
+    pub frame: SheetGrid,
+    pub sheet_indexing: SheetIndexer,
     pub direction_indexes: AnimationDirectionIndexes,
-    pub repeating: bool,
-    pub animation_timer: AnimationTimer,
+    pub loop_mode: LoopMode,
+    /// The position in `animation_frames` shown when the animation is stopped with a [StopAnimation]
+    pub rest_frame: usize,
+    /// Named markers on frames of the animation. The frame is the position in `animation_frames`
+    pub frame_markers: Vec<(usize, &'static str)>,
 }
 
 impl TimedAnimation {
     pub fn new(
         animation_frames: Vec<usize>,
         frame_timings_in_secs: Vec<f32>,
-        frame: Vec2,
+        frame: impl Into<SheetGrid>,
         direction_indexes: AnimationDirectionIndexes,
-        repeating: bool,
+        loop_mode: impl Into<LoopMode>,
         blocking: bool,
         blocking_priority: i32,
     ) -> Self {
-        let timer_dur = *frame_timings_in_secs.first().unwrap();
         Self {
-            animation_tick: 1,
             animation_frames,
             frame_timings_in_secs,
-            frame,
+            frame: frame.into(),
+            sheet_indexing: SheetIndexer::default(),
             direction_indexes,
-            repeating,
-            previous_dir_index: 1,
-            animation_timer: AnimationTimer(Timer::from_seconds(timer_dur, TimerMode::Repeating)),
+            loop_mode: loop_mode.into(),
+            rest_frame: 0,
+            frame_timings_in_ticks: Vec::new(),
+            time_source: None,
             blocking,
             blocking_priority,
+            frame_markers: Vec::new(),
         }
     }
 
-    fn get_x_index(&mut self) -> Option<usize> {
-        let index = self.animation_frames.get(self.animation_tick - 1);
-        if self.repeating {
-            match index {
-                Some(index) => Some(*index),
-                None => {
-                    self.animation_tick = 1;
-                    let index = self.animation_frames.get(self.animation_tick - 1)
-                    .unwrap_or_else(|| panic!("There was A Problem Cycling Animation\nThe index is {} but The Frame Length is {}", self.animation_tick, self.animation_frames.len()));
-                    Some(*index)
-                }
-            }
-        } else {
-            index.copied()
+    /// Sets how the frames are laid out in the sprite sheet. Defaults to [RowMajor]
+    pub fn with_sheet_indexing(mut self, sheet_indexing: impl SheetIndexing) -> Self {
+        self.sheet_indexing = SheetIndexer::new(sheet_indexing);
+        self
+    }
+
+    /// Times the animation with `time_source` instead of the one set on the [AnimationsPlugin]
+    pub fn with_time_source(mut self, time_source: AnimationTimeSource) -> Self {
+        self.time_source = Some(time_source);
+        self
+    }
+
+    /// Times the frames in game ticks instead of `frame_timings_in_secs`, which can be left empty
+    ///
+    /// A tick is one step of `Time<Fixed>`, so `6` ticks is a tenth of a second at 60 Hz
+    ///
+    /// # Panics
+    ///
+    /// If there isn't exactly one timing for each frame
+    pub fn with_tick_timings(mut self, frame_timings_in_ticks: Vec<u32>) -> Self {
+        assert_eq!(
+            frame_timings_in_ticks.len(),
+            self.animation_frames.len(),
+            "There should be one tick timing for each frame"
+        );
+        self.frame_timings_in_ticks = frame_timings_in_ticks;
+        self
+    }
+
+    /// Sets the position in `animation_frames` shown when the animation is stopped with a [StopAnimation]
+    pub fn with_rest_frame(mut self, rest_frame: usize) -> Self {
+        self.rest_frame = rest_frame;
+        self
+    }
+
+    /// A cursor on the first frame of the animation for an entity to play it with
+    pub fn cursor(&self) -> AnimationCursor {
+        let mut cursor = AnimationCursor {
+            animation_tick: 1,
+            previous_dir_index: 1,
+            ..Default::default()
+        };
+        self.restart_timer(&mut cursor, 0);
+        cursor
+    }
+
+    /// Moves `cursor` to the frame at `to`, which is shown for its whole timing before the animation moves on
+    ///
+    /// Returns the atlas index of the frame, see [SeekAnimation]
+    pub fn seek(
+        &self,
+        cursor: &mut AnimationCursor,
+        to: AnimationSeek,
+        direction: &AnimationDirection,
+    ) -> usize {
+        let position = to.position(self.animation_frames.len(), Some(&self.frame_lengths()));
+        cursor.progress = LoopProgress::default();
+        cursor.animation_tick = position + 1;
+        let index = self.sprite_index(cursor, direction);
+        self.restart_timer(cursor, position);
+        // The frame is put on the sprite through the `pending_index` of the playback
+        cursor.frame_shown = true;
+        index
+    }
+
+    /// A copy of this animation that only plays the frames of `slice`
+    ///
+    /// Returns [Result<Self, String>] an [Err(String)] if the frames of `slice` don't exist
+    pub fn slice(&self, slice: &AnimationSlice) -> Result<Self, String> {
+        let positions = slice.positions(self.animation_frames.len())?;
+        let mut animation = Self::new(
+            pick(&self.animation_frames, &positions),
+            pick(&self.frame_timings_in_secs, &positions),
+            self.frame,
+            self.direction_indexes,
+            slice.loop_mode.unwrap_or(self.loop_mode),
+            self.blocking,
+            self.blocking_priority,
+        );
+        animation.sheet_indexing = self.sheet_indexing.clone();
+        animation.frame_markers = slice_markers(&self.frame_markers, &positions);
+        animation.time_source = self.time_source;
+        if !self.frame_timings_in_ticks.is_empty() {
+            animation = animation.with_tick_timings(pick(&self.frame_timings_in_ticks, &positions));
         }
+        Ok(animation)
     }
 
-    fn ready_to_animate(&mut self, delta: Duration) -> bool {
-        self.animation_timer.tick(delta);
-        if self.animation_timer.is_finished() {
-            return true;
+    /// Fits the `cursor` of an entity playing `previous`, the definition this one replaced, to this one
+    ///
+    /// The current frame is kept and timed with its new timing, keeping the time it was already shown for but never more than
+    /// its new timing. The loops played so far are kept unless the [LoopMode] changed.
+    /// If the current frame doesn't exist in this definition the animation starts over
+    pub fn reload_cursor(&self, previous: &Self, cursor: &mut AnimationCursor) {
+        let Some(position) = kept_position(cursor.animation_tick, 1, self.animation_frames.len())
+        else {
+            *cursor = AnimationCursor {
+                previous_dir_index: cursor.previous_dir_index,
+                ..self.cursor()
+            };
+            return;
+        };
+        if self.loop_mode != previous.loop_mode {
+            cursor.progress = LoopProgress::default();
+        }
+        self.retime_frame(cursor, position);
+    }
+
+    /// Adds a named marker to a frame. An [AnimationFrameEvent] is sent every time the frame is shown
+    ///
+    /// `frame` is the position in `animation_frames`, not the atlas index
+    pub fn with_marker(mut self, frame: usize, marker: &'static str) -> Self {
+        self.frame_markers.push((frame, marker));
+        self
+    }
+
+    /// The markers on the frame at position `frame` in `animation_frames`
+    pub fn markers(&self, frame: usize) -> impl Iterator<Item = &'static str> + '_ {
+        self.frame_markers
+            .iter()
+            .filter(move |(marker_frame, _)| *marker_frame == frame)
+            .map(|(_, marker)| *marker)
+    }
+
+    /// Adds the time that passed to the seconds or ticks the animation is timed in
+    fn accumulate(&self, cursor: &mut AnimationCursor, delta: AnimationDelta) {
+        match self.frame_timings_in_ticks.is_empty() {
+            true => cursor.animation_timer.accumulate(delta.time),
+            false => cursor.tick_timer.accumulate(delta.ticks),
+        }
+    }
+
+    /// Takes the timing of the current frame out of the time built up
+    fn take_frame(&self, cursor: &mut AnimationCursor) -> bool {
+        match self.frame_timings_in_ticks.is_empty() {
+            true => cursor.animation_timer.take_frame(),
+            false => cursor.tick_timer.take_frame(),
         }
-        false
     }
 
-    pub fn sprite_index(&mut self, direction: &AnimationDirection) -> usize {
-        let x_index = self.get_x_index().unwrap_or_default();
+    /// Times the frame at `position` in `animation_frames`. Returns `false` if the frame has no timing
+    fn time_frame(&self, cursor: &mut AnimationCursor, position: usize) -> bool {
+        if !self.frame_timings_in_ticks.is_empty() {
+            let ticks = self.frame_timings_in_ticks[position];
+            cursor.tick_timer.set_duration(ticks);
+            return ticks > 0;
+        }
+        let timing = *self.frame_timings_in_secs.get(position).unwrap_or_else(|| {
+            panic!("Frame {position} has no timing, add it to `frame_timings_in_secs` or use `with_tick_timings`")
+        });
+        cursor
+            .animation_timer
+            .set_duration(Duration::from_secs_f32(timing));
+        timing > 0.
+    }
+
+    /// Times the frame at `position` from the start, throwing away any time built up
+    fn restart_timer(&self, cursor: &mut AnimationCursor, position: usize) {
+        self.time_frame(cursor, position);
+        cursor.animation_timer.reset();
+        cursor.tick_timer.reset();
+    }
+
+    /// Times the frame at `position` again after the timings changed. The time built up is kept up to the frame's new timing
+    fn retime_frame(&self, cursor: &mut AnimationCursor, position: usize) {
+        self.time_frame(cursor, position);
+        cursor.animation_timer.clamp_to_duration();
+        cursor.tick_timer.clamp_to_duration();
+    }
+
+    /// How long each frame is in the unit the animation is timed in
+    fn frame_lengths(&self) -> Vec<f32> {
+        match self.frame_timings_in_ticks.is_empty() {
+            true => self.frame_timings_in_secs.clone(),
+            false => self
+                .frame_timings_in_ticks
+                .iter()
+                .map(|ticks| *ticks as f32)
+                .collect(),
+        }
+    }
+
+    fn get_x_index(&self, cursor: &AnimationCursor) -> Option<usize> {
+        if cursor.progress.ended() {
+            return None;
+        }
+        self.animation_frames
+            .get(cursor.animation_tick - 1)
+            .copied()
+    }
 
-        match self.get_y_index(direction) {
+    pub fn sprite_index(
+        &self,
+        cursor: &mut AnimationCursor,
+        direction: &AnimationDirection,
+    ) -> usize {
+        let x_index = self.get_x_index(cursor).unwrap_or_default();
+
+        match self.get_y_index(cursor, direction) {
             YIndex::Index(y_index) => {
-                // (y_index * self.frame.y as usize) - (self.frame.x as usize - x_index)
-                y_index * self.frame.x as usize + x_index
+                self.sheet_indexing
+                    .atlas_index(x_index, y_index, &self.frame)
             }
             YIndex::Flip(_, y_index) => {
-                // (y_index * self.frame.y as usize) - (self.frame.x as usize - x_index)
-                y_index * self.frame.x as usize + x_index
+                self.sheet_indexing
+                    .atlas_index(x_index, y_index, &self.frame)
             }
         }
     }
 
     pub fn cycle_animation(
-        &mut self,
+        &self,
+        cursor: &mut AnimationCursor,
         mut sprite: Mut<Sprite>,
         direction: &AnimationDirection,
-        delta: Duration,
+        delta: AnimationDelta,
     ) -> Option<()> {
-        if self.ready_to_animate(delta) {
-            let y_index = match self.get_y_index(direction) {
-                YIndex::Index(y_index) => y_index,
-                YIndex::Flip(flipped, y_index) => {
-                    sprite.flip_x = flipped;
-                    y_index
-                }
-            };
-            self.previous_dir_index = y_index;
-            let x_index = match self.get_x_index() {
-                Some(index) => index,
-                None => {
-                    self.animation_tick = 1;
-                    return None;
-                }
-            };
-            // let index = (y_index * self.frame.y as usize) - (self.frame.x as usize - x_index);
-            let index = y_index * self.frame.x as usize + x_index;
-            sprite
-                .texture_atlas
-                .as_mut()
-                .expect("Fatal animation error! No texture_atlas set for entity")
-                .index = index;
-            let timing = *self
-                .frame_timings_in_secs
-                .get(self.animation_tick - 1)
-                .expect("Error With Animation Timing");
-            self.animation_timer
-                .set_duration(Duration::from_secs_f32(timing));
-            self.animation_timer.reset();
-            self.animation_tick += 1;
+        if cursor.progress.ended() {
+            return cursor.progress.finish(self.loop_mode);
+        }
+        // The time that passed before the animation started doesn't count towards its first frame
+        if !cursor.frame_shown {
+            self.show_frame(cursor, &mut sprite, direction);
             return Some(());
         }
+        self.accumulate(cursor, delta);
+        // Move on every time the current frame's time has passed so the animation plays at the same pace at any frame rate
+        while self.take_frame(cursor) {
+            let position = cursor.progress.advance(
+                self.loop_mode,
+                cursor.animation_tick - 1,
+                self.animation_frames.len(),
+            );
+            if cursor.progress.ended() {
+                let finished = cursor.progress.finish(self.loop_mode);
+                if !cursor.progress.is_holding() {
+                    cursor.animation_tick = 1;
+                    cursor.frame_shown = false;
+                    self.restart_timer(cursor, 0);
+                }
+                return finished;
+            }
+            cursor.animation_tick = position + 1;
+            // Frames without a timing would never run out of time to catch up on
+            if !self.show_frame(cursor, &mut sprite, direction) {
+                break;
+            }
+        }
         Some(())
     }
 
+    /// Puts the frame the cursor is on on the sprite and times it. Returns `false` if the frame has no timing
+    fn show_frame(
+        &self,
+        cursor: &mut AnimationCursor,
+        sprite: &mut Mut<Sprite>,
+        direction: &AnimationDirection,
+    ) -> bool {
+        let position = cursor.animation_tick - 1;
+        let y_index = match self.get_y_index(cursor, direction) {
+            YIndex::Index(y_index) => y_index,
+            YIndex::Flip(flipped, y_index) => {
+                sprite.flip_x = flipped;
+                y_index
+            }
+        };
+        cursor.previous_dir_index = y_index;
+        let index =
+            self.sheet_indexing
+                .atlas_index(self.animation_frames[position], y_index, &self.frame);
+        sprite
+            .texture_atlas
+            .as_mut()
+            .expect("Fatal animation error! No texture_atlas set for entity")
+            .index = index;
+        cursor.shown_frames.push(position);
+        cursor.frame_shown = true;
+        cursor.progress.frame_shown();
+        self.time_frame(cursor, position)
+    }
+
     pub fn reset_animation(
-        &mut self,
+        &self,
+        cursor: &mut AnimationCursor,
         sprite: Option<Mut<Sprite>>,
         direction: Option<&AnimationDirection>,
     ) {
-        self.animation_tick = 1;
-        let new_dur = Duration::from_secs_f32(
-            *self
-                .frame_timings_in_secs
-                .first()
-                .expect("Error With Animation Timing"),
-        );
-        self.animation_timer.set_duration(new_dur);
-        self.animation_timer.reset();
+        cursor.animation_tick = 1;
+        cursor.progress = LoopProgress::default();
+        self.restart_timer(cursor, 0);
+        // Cycling the animation puts the first frame on the sprite again and sends its markers
+        cursor.frame_shown = false;
 
         if let (Some(mut sprite), Some(direction)) = (sprite, direction) {
             let x_index = self
-                .get_x_index()
+                .get_x_index(cursor)
                 .expect("Something Went Wrong Reseting Animation");
             let new_index;
-            match self.get_y_index(direction) {
+            match self.get_y_index(cursor, direction) {
                 YIndex::Index(y_index) => {
-                    new_index = y_index * self.frame.x as usize + x_index;
+                    new_index = self
+                        .sheet_indexing
+                        .atlas_index(x_index, y_index, &self.frame);
                 }
                 YIndex::Flip(flip, y_index) => {
                     sprite.flip_x = flip;
-                    new_index = y_index * self.frame.x as usize + x_index;
+                    new_index = self
+                        .sheet_indexing
+                        .atlas_index(x_index, y_index, &self.frame);
                 }
             }
             sprite
@@ -206,7 +410,7 @@ impl TimedAnimation {
     }
 
     #[allow(unused)]
-    fn get_y_index(&self, direction: &AnimationDirection) -> YIndex {
+    fn get_y_index(&self, cursor: &AnimationCursor, direction: &AnimationDirection) -> YIndex {
         match (direction, self.direction_indexes) {
             (AnimationDirection::Left, AnimationDirectionIndexes::IndexBased(index)) => {
                 YIndex::Index(index.left)
@@ -232,7 +436,7 @@ impl TimedAnimation {
             (_, AnimationDirectionIndexes::FX(fx_based_animation)) => {
                 YIndex::Index(fx_based_animation.index)
             }
-            (AnimationDirection::Still, _) => YIndex::Index(self.previous_dir_index),
+            (AnimationDirection::Still, _) => YIndex::Index(cursor.previous_dir_index),
             (_, _) => YIndex::Index(1),
         }
     }
@@ -246,26 +450,23 @@ impl TimedAnimation {
 /// fn init_animation(
 ///     mut animations: ResMut<Animations>,
 ///     mut commands: Commands,
-///     asset_server: ResMut<AssetServer>,
-///     mut texture_atlases: ResMut<Assets<TextureAtlas>>,
+///     asset_server: Res<AssetServer>,
+///     mut layouts: ResMut<Assets<TextureAtlasLayout>>,
 /// ) {
-///     let asset = asset_server.load("path/to/your/sprite_sheet");
-///
-///     let texture_atlas = TextureAtlas::from_grid(asset, Vec2::new(16.0, 16.0), 10, 1, None, None);
+///     let image = asset_server.load("path/to/your/sprite_sheet.png");
 ///
-///     let texture_atlas_handle = texture_atlases.add(texture_atlas);
+///     let layout = layouts.add(TextureAtlasLayout::from_grid(UVec2::new(16, 16), 10, 1, None, None));
 ///
 ///     let entity = commands
-///         .spawn(AnimationDirection::default())
-///         .insert(SpriteSheetBundle {
-///             texture_atlas: texture_atlas_handle.clone(),
-///             ..Default::default()
-///         })
+///         .spawn((
+///             Animator::default(),
+///             Sprite::from_atlas_image(image.clone(), TextureAtlas::from(layout.clone())),
+///         ))
 ///         .id();
 ///     animations.insert_animation(
 ///         NewAnimation {
-///             handle: texture_atlas_handle.clone(), /* the handle for the TextureAtlas */
-///             animation: AnimationType::Timed(
+///             handles: Handles::new(image, layout), /* the sprite sheet and its layout */
+///             animation: AnimationType::Transform(
 ///                 TransformAnimation::new(
 ///                     Vec::from(PLAYER_RUNNING_FRAMES), /* animation_frames */
 ///                     PLAYER_RUNNING_METERS_PER_FRAME,  /* meters_per_frame */
@@ -275,7 +476,7 @@ impl TimedAnimation {
 ///                         left_direction_is_flipped: true,
 ///                         x_direction_index: 3,
 ///                     }),
-///                     true, /* repeating */
+///                     LoopMode::Loop, /* loop_mode */
 ///                 ),
 ///                 "player_running", /* AnimationName */
 ///         },
@@ -285,39 +486,113 @@ impl TimedAnimation {
 /// ````
 #[derive(Debug, Default, Clone)]
 pub struct TransformAnimation {
-    animation_tick: usize,
-    previous_dir_index: usize,
-    previous_transform: Transform,
     pub animation_frames: Vec<usize>,
     pub meters_per_frame: f32,
-    pub frame: Vec2,
+    pub frame: SheetGrid,
+    pub sheet_indexing: SheetIndexer,
     pub direction_indexes: AnimationDirectionIndexes,
-    pub repeating: bool,
+    pub loop_mode: LoopMode,
+    /// The position in `animation_frames` shown when the animation is stopped with a [StopAnimation]
+    pub rest_frame: usize,
 }
 
 impl TransformAnimation {
     pub fn new(
         animation_frames: Vec<usize>,
         meters_per_frame: f32,
-        frame: Vec2,
+        frame: impl Into<SheetGrid>,
         direction_indexes: AnimationDirectionIndexes,
-        repeating: bool,
+        loop_mode: impl Into<LoopMode>,
     ) -> Self {
         Self {
-            animation_tick: 1,
-            previous_dir_index: 1,
-            previous_transform: Transform::from_xyz(0., 0., 0.),
             animation_frames,
             meters_per_frame,
-            frame,
+            frame: frame.into(),
+            sheet_indexing: SheetIndexer::default(),
             direction_indexes,
-            repeating,
+            loop_mode: loop_mode.into(),
+            rest_frame: 0,
         }
     }
 
-    fn ready_to_animate(&self, transform: &Mut<Transform>, pixels_per_meter: f32) -> bool {
-        let x_diff = (transform.translation.x - self.previous_transform.translation.x).abs();
-        let y_diff = (transform.translation.y - self.previous_transform.translation.y).abs();
+    /// Sets how the frames are laid out in the sprite sheet. Defaults to [RowMajor]
+    pub fn with_sheet_indexing(mut self, sheet_indexing: impl SheetIndexing) -> Self {
+        self.sheet_indexing = SheetIndexer::new(sheet_indexing);
+        self
+    }
+
+    /// Sets the position in `animation_frames` shown when the animation is stopped with a [StopAnimation]
+    pub fn with_rest_frame(mut self, rest_frame: usize) -> Self {
+        self.rest_frame = rest_frame;
+        self
+    }
+
+    /// A cursor on the first frame of the animation for an entity to play it with
+    pub fn cursor(&self) -> AnimationCursor {
+        AnimationCursor {
+            animation_tick: 1,
+            previous_dir_index: 1,
+            ..Default::default()
+        }
+    }
+
+    /// Moves `cursor` to the frame at `to`, which is the next frame shown so the animation plays from there like it does from its first frame
+    ///
+    /// Returns the atlas index of the frame, see [SeekAnimation]
+    pub fn seek(
+        &self,
+        cursor: &mut AnimationCursor,
+        to: AnimationSeek,
+        direction: &AnimationDirection,
+    ) -> usize {
+        let position = to.position(self.animation_frames.len(), None);
+        cursor.progress = LoopProgress::default();
+        cursor.animation_tick = position + 1;
+        self.sprite_index(cursor, direction)
+    }
+
+    /// A copy of this animation that only plays the frames of `slice`
+    ///
+    /// Returns [Result<Self, String>] an [Err(String)] if the frames of `slice` don't exist
+    pub fn slice(&self, slice: &AnimationSlice) -> Result<Self, String> {
+        let positions = slice.positions(self.animation_frames.len())?;
+        let mut animation = Self::new(
+            pick(&self.animation_frames, &positions),
+            self.meters_per_frame,
+            self.frame,
+            self.direction_indexes,
+            slice.loop_mode.unwrap_or(self.loop_mode),
+        );
+        animation.sheet_indexing = self.sheet_indexing.clone();
+        Ok(animation)
+    }
+
+    /// Fits the `cursor` of an entity playing `previous`, the definition this one replaced, to this one
+    ///
+    /// The current frame is kept and so are the loops played so far unless the [LoopMode] changed.
+    /// If the current frame doesn't exist in this definition the animation starts over
+    pub fn reload_cursor(&self, previous: &Self, cursor: &mut AnimationCursor) {
+        if kept_position(cursor.animation_tick, 1, self.animation_frames.len()).is_none() {
+            *cursor = AnimationCursor {
+                previous_dir_index: cursor.previous_dir_index,
+                previous_transform: cursor.previous_transform,
+                ..self.cursor()
+            };
+            return;
+        }
+        if self.loop_mode != previous.loop_mode {
+            cursor.progress = LoopProgress::default();
+        }
+    }
+
+    fn ready_to_animate(
+        &self,
+        cursor: &AnimationCursor,
+        transform: &Mut<Transform>,
+        pixels_per_meter: f32,
+    ) -> bool {
+        let x_diff = (transform.translation.x - cursor.previous_transform.translation.x).abs();
+        let y_diff = (transform.translation.y - cursor.previous_transform.translation.y).abs();
 
         let modifier = pixels_per_meter * self.meters_per_frame;
 
@@ -327,34 +602,49 @@ impl TransformAnimation {
         false
     }
 
-    pub fn sprite_index(&mut self, direction: &AnimationDirection) -> usize {
-        let x_index = self.get_x_index().unwrap_or_default();
-        let y_index = match self.get_y_index(direction) {
+    pub fn sprite_index(
+        &self,
+        cursor: &mut AnimationCursor,
+        direction: &AnimationDirection,
+    ) -> usize {
+        let x_index = self.get_x_index(cursor).unwrap_or_default();
+        let y_index = match self.get_y_index(cursor, direction) {
             YIndex::Index(y_index) => y_index,
             YIndex::Flip(_, y_index) => y_index,
         };
-        // (y_index * self.frame.y as usize) - (self.frame.x as usize - x_index)
-        y_index * self.frame.x as usize + x_index
+        self.sheet_indexing
+            .atlas_index(x_index, y_index, &self.frame)
     }
 
     pub fn cycle_animation(
-        &mut self,
+        &self,
+        cursor: &mut AnimationCursor,
         mut sprite: Mut<Sprite>,
         direction: &AnimationDirection,
         transform: Mut<Transform>,
         pixels_per_meter: f32,
         // name: &'static str
     ) -> Option<()> {
-        let y_index = match self.get_y_index(direction) {
+        let y_index = match self.get_y_index(cursor, direction) {
             YIndex::Index(y_index) => y_index,
             YIndex::Flip(_, y_index) => y_index,
         };
-        if self.ready_to_animate(&transform, pixels_per_meter) || y_index != self.previous_dir_index
+        if self.ready_to_animate(cursor, &transform, pixels_per_meter)
+            || y_index != cursor.previous_dir_index
         {
-            self.previous_transform = *transform;
-            let x_index = self.get_x_index()?;
+            cursor.previous_transform = *transform;
+            let x_index = match self.get_x_index(cursor) {
+                Some(index) => index,
+                None => {
+                    let finished = cursor.progress.finish(self.loop_mode);
+                    if !cursor.progress.is_holding() {
+                        cursor.animation_tick = 1;
+                    }
+                    return finished;
+                }
+            };
 
-            let y_index = match self.get_y_index(direction) {
+            let y_index = match self.get_y_index(cursor, direction) {
                 YIndex::Index(y_index) => y_index,
                 YIndex::Flip(flipped, y_index) => {
                     sprite.flip_x = flipped;
@@ -362,53 +652,52 @@ impl TransformAnimation {
                 }
             };
 
-            self.previous_dir_index = y_index;
+            cursor.previous_dir_index = y_index;
 
-            // let index = (y_index * self.frame.y as usize) - (self.frame.x as usize - x_index);
-            let index = y_index * self.frame.x as usize + x_index;
+            let index = self
+                .sheet_indexing
+                .atlas_index(x_index, y_index, &self.frame);
             sprite
                 .texture_atlas
                 .as_mut()
                 .expect("Fatal animation error! No texture_atlas set for entity")
                 .index = index;
 
-            self.animation_tick += 1;
+            cursor.progress.frame_shown();
+            cursor.animation_tick = cursor.progress.advance(
+                self.loop_mode,
+                cursor.animation_tick - 1,
+                self.animation_frames.len(),
+            ) + 1;
             return Some(());
         } else if *direction == AnimationDirection::Still {
             let x_index = self.animation_frames.first().unwrap();
 
-            let y_index = self.previous_dir_index;
+            let y_index = cursor.previous_dir_index;
 
-            // texture_atlas.index = (y_index * self.frame.y as usize) - (self.frame.x as usize - x_index);
             sprite
                 .texture_atlas
                 .as_mut()
                 .expect("Fatal animation error! No texture_atlas set for entity")
-                .index = y_index * self.frame.x as usize + x_index;
+                .index = self
+                .sheet_indexing
+                .atlas_index(*x_index, y_index, &self.frame);
             return Some(());
         }
         Some(())
     }
 
-    fn get_x_index(&mut self) -> Option<usize> {
-        let index = self.animation_frames.get(self.animation_tick - 1);
-        if self.repeating {
-            match index {
-                Some(index) => Some(*index),
-                None => {
-                    self.animation_tick = 1;
-                    let index = self.animation_frames.get(self.animation_tick)
-                        .unwrap_or_else(|| panic!("There was A Problem Cycling Animation\nThe index is {} but The Frame Length is {}", self.animation_tick, self.animation_frames.len()));
-                    Some(*index)
-                }
-            }
-        } else {
-            index.copied()
+    fn get_x_index(&self, cursor: &AnimationCursor) -> Option<usize> {
+        if cursor.progress.ended() {
+            return None;
         }
+        self.animation_frames
+            .get(cursor.animation_tick - 1)
+            .copied()
     }
 
     #[allow(unused)]
-    fn get_y_index(&self, direction: &AnimationDirection) -> YIndex {
+    fn get_y_index(&self, cursor: &AnimationCursor, direction: &AnimationDirection) -> YIndex {
         match (direction, self.direction_indexes) {
             (AnimationDirection::Left, AnimationDirectionIndexes::IndexBased(index)) => {
                 YIndex::Index(index.left)
@@ -431,29 +720,35 @@ impl TransformAnimation {
             (_, AnimationDirectionIndexes::FX(fx_based_animation)) => {
                 YIndex::Index(fx_based_animation.index)
             }
-            (AnimationDirection::Still, _) => YIndex::Index(self.previous_dir_index),
+            (AnimationDirection::Still, _) => YIndex::Index(cursor.previous_dir_index),
             (_, _) => YIndex::Index(1),
         }
     }
 
     pub fn reset_animation(
-        &mut self,
+        &self,
+        cursor: &mut AnimationCursor,
         sprite: Option<Mut<Sprite>>,
         direction: Option<&AnimationDirection>,
     ) {
-        self.animation_tick = 1;
+        cursor.animation_tick = 1;
+        cursor.progress = LoopProgress::default();
         if let (Some(mut sprite), Some(direction)) = (sprite, direction) {
             let x_index = self
-                .get_x_index()
+                .get_x_index(cursor)
                 .expect("Something Went Wrong Reseting Animation");
             let new_index;
-            match self.get_y_index(direction) {
+            match self.get_y_index(cursor, direction) {
                 YIndex::Index(y_index) => {
-                    new_index = y_index * self.frame.x as usize + x_index;
+                    new_index = self
+                        .sheet_indexing
+                        .atlas_index(x_index, y_index, &self.frame);
                 }
                 YIndex::Flip(flip, y_index) => {
                     sprite.flip_x = flip;
-                    new_index = y_index * self.frame.x as usize + x_index;
+                    new_index = self
+                        .sheet_indexing
+                        .atlas_index(x_index, y_index, &self.frame);
                 }
             }
             sprite
@@ -474,30 +769,27 @@ impl TransformAnimation {
 /// fn init_animation(
 ///     mut animations: ResMut<Animations>,
 ///     mut commands: Commands,
-///     asset_server: ResMut<AssetServer>,
-///     mut texture_atlases: ResMut<Assets<TextureAtlas>>,
+///     asset_server: Res<AssetServer>,
+///     mut layouts: ResMut<Assets<TextureAtlasLayout>>,
 /// ) {
-///     let asset = asset_server.load("path/to/your/sprite_sheet");
+///     let image = asset_server.load("path/to/your/sprite_sheet.png");
 ///
-///     let texture_atlas = TextureAtlas::from_grid(asset, Vec2::new(16.0, 16.0), 10, 1, None, None);
-///
-///     let texture_atlas_handle = texture_atlases.add(texture_atlas);
+///     let layout = layouts.add(TextureAtlasLayout::from_grid(UVec2::new(16, 16), 10, 1, None, None));
 ///
 ///     let entity = commands
-///         .spawn(AnimationDirection::default())
-///         .insert(SpriteSheetBundle {
-///             texture_atlas: texture_atlas_handle.clone(),
-///             ..Default::default()
-///         })
+///         .spawn((
+///             Animator::default(),
+///             Sprite::from_atlas_image(image.clone(), TextureAtlas::from(layout.clone())),
+///         ))
 ///         .id();
 ///     animations.insert_animation(
 ///         NewAnimation {
-///             handle: texture_atlas_handle.clone(), /* the handle for the TextureAtlas */
+///             handles: Handles::new(image, layout), /* the sprite sheet and its layout */
 ///             animation: AnimationType::LinearTimed(
-///                 LinearTimedAnimtion::new(
+///                 LinearTimedAnimation::new(
 ///                     Vec::from(PLAYER_RUNNING_FRAMES), /* animation_frames */
-///                     Vec::from(PLYAER_FRAME_TIMING),   /* frame_timings_in_secs */
-///                     true,                             /* repeating */
+///                     Vec::from(PLAYER_FRAME_TIMINGS),   /* frame_timings_in_secs */
+///                     LoopMode::Loop,                   /* loop_mode */
 ///                 ),
 ///                 "player_running", /* AnimationName */
 ///             ),
@@ -508,89 +800,278 @@ impl TransformAnimation {
 /// ```
 #[derive(Debug, Default, Clone)]
 pub struct LinearTimedAnimation {
-    animation_tick: usize,
-    animation_timer: AnimationTimer,
     pub frame_timings_in_secs: Vec<f32>,
+    /// When not empty the frames are timed in ticks of `Time<Fixed>` instead of `frame_timings_in_secs`
+    pub frame_timings_in_ticks: Vec<u32>,
+    /// The clock the frames are timed with. [None] uses the one set on the [AnimationsPlugin]
+    pub time_source: Option<AnimationTimeSource>,
     pub animation_frames: Vec<usize>,
-    pub repeating: bool,
+    pub loop_mode: LoopMode,
+    /// The position in `animation_frames` shown when the animation is stopped with a [StopAnimation]
+    pub rest_frame: usize,
+    /// Named markers on frames of the animation. The frame is the position in `animation_frames`
+    pub frame_markers: Vec<(usize, &'static str)>,
 }
 
 impl LinearTimedAnimation {
     pub fn new(
         animation_frames: Vec<usize>,
         frame_timings_in_secs: Vec<f32>,
-        repeating: bool,
+        loop_mode: impl Into<LoopMode>,
     ) -> Self {
         Self {
-            animation_tick: 1,
-            animation_timer: AnimationTimer(Timer::from_seconds(
-                *frame_timings_in_secs
-                    .first()
-                    .expect("Something went wrong retrieving timings"),
-                TimerMode::Repeating,
-            )),
             animation_frames,
             frame_timings_in_secs,
-            repeating,
+            frame_timings_in_ticks: Vec::new(),
+            time_source: None,
+            loop_mode: loop_mode.into(),
+            rest_frame: 0,
+            frame_markers: Vec::new(),
         }
     }
 
-    fn get_x_index(&mut self) -> Option<usize> {
-        match self.animation_frames.get(self.animation_tick) {
-            Some(index) => Some(*index),
-            None => {
-                self.animation_tick = 1;
-                if self.repeating {
-                    return Some(0);
-                }
-                None
-            }
+    /// Times the animation with `time_source` instead of the one set on the [AnimationsPlugin]
+    pub fn with_time_source(mut self, time_source: AnimationTimeSource) -> Self {
+        self.time_source = Some(time_source);
+        self
+    }
+
+    /// Times the frames in game ticks instead of `frame_timings_in_secs`, which can be left empty
+    ///
+    /// A tick is one step of `Time<Fixed>`, so `6` ticks is a tenth of a second at 60 Hz
+    ///
+    /// # Panics
+    ///
+    /// If there isn't exactly one timing for each frame
+    pub fn with_tick_timings(mut self, frame_timings_in_ticks: Vec<u32>) -> Self {
+        assert_eq!(
+            frame_timings_in_ticks.len(),
+            self.animation_frames.len(),
+            "There should be one tick timing for each frame"
+        );
+        self.frame_timings_in_ticks = frame_timings_in_ticks;
+        self
+    }
+
+    /// Sets the position in `animation_frames` shown when the animation is stopped with a [StopAnimation]
+    pub fn with_rest_frame(mut self, rest_frame: usize) -> Self {
+        self.rest_frame = rest_frame;
+        self
+    }
+
+    /// A cursor on the first frame of the animation for an entity to play it with
+    pub fn cursor(&self) -> AnimationCursor {
+        let mut cursor = AnimationCursor::default();
+        self.restart_timer(&mut cursor, 0);
+        cursor
+    }
+
+    /// Moves `cursor` to the frame at `to`, which is shown for its whole timing before the animation moves on
+    ///
+    /// Returns the atlas index of the frame, see [SeekAnimation]
+    pub fn seek(
+        &self,
+        cursor: &mut AnimationCursor,
+        to: AnimationSeek,
+        direction: &AnimationDirection,
+    ) -> usize {
+        let position = to.position(self.animation_frames.len(), Some(&self.frame_lengths()));
+        cursor.progress = LoopProgress::default();
+        cursor.animation_tick = position;
+        let index = self.sprite_index(cursor, direction);
+        self.restart_timer(cursor, position);
+        // The frame is put on the sprite through the `pending_index` of the playback
+        cursor.frame_shown = true;
+        index
+    }
+
+    /// A copy of this animation that only plays the frames of `slice`
+    ///
+    /// Returns [Result<Self, String>] an [Err(String)] if the frames of `slice` don't exist
+    pub fn slice(&self, slice: &AnimationSlice) -> Result<Self, String> {
+        let positions = slice.positions(self.animation_frames.len())?;
+        let mut animation = Self::new(
+            pick(&self.animation_frames, &positions),
+            pick(&self.frame_timings_in_secs, &positions),
+            slice.loop_mode.unwrap_or(self.loop_mode),
+        );
+        animation.frame_markers = slice_markers(&self.frame_markers, &positions);
+        animation.time_source = self.time_source;
+        if !self.frame_timings_in_ticks.is_empty() {
+            animation = animation.with_tick_timings(pick(&self.frame_timings_in_ticks, &positions));
         }
+        Ok(animation)
     }
 
-    pub fn sprite_index(&mut self, _direction: &AnimationDirection) -> usize {
-        self.get_x_index().unwrap_or_default()
+    /// Fits the `cursor` of an entity playing `previous`, the definition this one replaced, to this one
+    ///
+    /// The current frame is kept and timed with its new timing, keeping the time it was already shown for but never more than
+    /// its new timing. The loops played so far are kept unless the [LoopMode] changed.
+    /// If the current frame doesn't exist in this definition the animation starts over
+    pub fn reload_cursor(&self, previous: &Self, cursor: &mut AnimationCursor) {
+        let Some(position) = kept_position(cursor.animation_tick, 0, self.animation_frames.len())
+        else {
+            *cursor = self.cursor();
+            return;
+        };
+        if self.loop_mode != previous.loop_mode {
+            cursor.progress = LoopProgress::default();
+        }
+        self.retime_frame(cursor, position);
     }
 
-    pub fn cycle_animation(&mut self, mut sprite: Mut<Sprite>, delta: Duration) -> Option<()> {
-        self.animation_timer.tick(delta);
-        if self.animation_timer.is_finished() {
-            let x_index = self.get_x_index()?;
+    /// Adds a named marker to a frame. An [AnimationFrameEvent] is sent every time the frame is shown
+    ///
+    /// `frame` is the position in `animation_frames`, not the atlas index
+    pub fn with_marker(mut self, frame: usize, marker: &'static str) -> Self {
+        self.frame_markers.push((frame, marker));
+        self
+    }
 
-            let new_dur = Duration::from_secs_f32(
-                *self
-                    .frame_timings_in_secs
-                    .get(self.animation_tick)
-                    .expect("There Was A Problem Getting New Timing Check Your Timing Configs"),
-            );
-            self.animation_timer.set_duration(new_dur);
-            self.animation_timer.reset();
-            sprite
-                .texture_atlas
-                .as_mut()
-                .expect("Fatal animation error! No texture_atlas set for entity")
-                .index = x_index;
+    /// The markers on the frame at position `frame` in `animation_frames`
+    pub fn markers(&self, frame: usize) -> impl Iterator<Item = &'static str> + '_ {
+        self.frame_markers
+            .iter()
+            .filter(move |(marker_frame, _)| *marker_frame == frame)
+            .map(|(_, marker)| *marker)
+    }
 
-            self.animation_tick += 1;
+    /// Adds the time that passed to the seconds or ticks the animation is timed in
+    fn accumulate(&self, cursor: &mut AnimationCursor, delta: AnimationDelta) {
+        match self.frame_timings_in_ticks.is_empty() {
+            true => cursor.animation_timer.accumulate(delta.time),
+            false => cursor.tick_timer.accumulate(delta.ticks),
+        }
+    }
 
+    /// Takes the timing of the current frame out of the time built up
+    fn take_frame(&self, cursor: &mut AnimationCursor) -> bool {
+        match self.frame_timings_in_ticks.is_empty() {
+            true => cursor.animation_timer.take_frame(),
+            false => cursor.tick_timer.take_frame(),
+        }
+    }
+
+    /// Times the frame at `position` in `animation_frames`. Returns `false` if the frame has no timing
+    fn time_frame(&self, cursor: &mut AnimationCursor, position: usize) -> bool {
+        if !self.frame_timings_in_ticks.is_empty() {
+            let ticks = self.frame_timings_in_ticks[position];
+            cursor.tick_timer.set_duration(ticks);
+            return ticks > 0;
+        }
+        let timing = *self.frame_timings_in_secs.get(position).unwrap_or_else(|| {
+            panic!("Frame {position} has no timing, add it to `frame_timings_in_secs` or use `with_tick_timings`")
+        });
+        cursor
+            .animation_timer
+            .set_duration(Duration::from_secs_f32(timing));
+        timing > 0.
+    }
+
+    /// Times the frame at `position` from the start, throwing away any time built up
+    fn restart_timer(&self, cursor: &mut AnimationCursor, position: usize) {
+        self.time_frame(cursor, position);
+        cursor.animation_timer.reset();
+        cursor.tick_timer.reset();
+    }
+
+    /// Times the frame at `position` again after the timings changed. The time built up is kept up to the frame's new timing
+    fn retime_frame(&self, cursor: &mut AnimationCursor, position: usize) {
+        self.time_frame(cursor, position);
+        cursor.animation_timer.clamp_to_duration();
+        cursor.tick_timer.clamp_to_duration();
+    }
+
+    /// How long each frame is in the unit the animation is timed in
+    fn frame_lengths(&self) -> Vec<f32> {
+        match self.frame_timings_in_ticks.is_empty() {
+            true => self.frame_timings_in_secs.clone(),
+            false => self
+                .frame_timings_in_ticks
+                .iter()
+                .map(|ticks| *ticks as f32)
+                .collect(),
+        }
+    }
+
+    fn get_x_index(&self, cursor: &AnimationCursor) -> Option<usize> {
+        if cursor.progress.ended() {
+            return None;
+        }
+        self.animation_frames.get(cursor.animation_tick).copied()
+    }
+
+    pub fn sprite_index(
+        &self,
+        cursor: &mut AnimationCursor,
+        _direction: &AnimationDirection,
+    ) -> usize {
+        self.get_x_index(cursor).unwrap_or_default()
+    }
+
+    pub fn cycle_animation(
+        &self,
+        cursor: &mut AnimationCursor,
+        mut sprite: Mut<Sprite>,
+        delta: AnimationDelta,
+    ) -> Option<()> {
+        if cursor.progress.ended() {
+            return cursor.progress.finish(self.loop_mode);
+        }
+        // The time that passed before the animation started doesn't count towards its first frame
+        if !cursor.frame_shown {
+            self.show_frame(cursor, &mut sprite);
             return Some(());
         }
+        self.accumulate(cursor, delta);
+        // Move on every time the current frame's time has passed so the animation plays at the same pace at any frame rate
+        while self.take_frame(cursor) {
+            let position = cursor.progress.advance(
+                self.loop_mode,
+                cursor.animation_tick,
+                self.animation_frames.len(),
+            );
+            if cursor.progress.ended() {
+                let finished = cursor.progress.finish(self.loop_mode);
+                if !cursor.progress.is_holding() {
+                    cursor.animation_tick = 0;
+                    cursor.frame_shown = false;
+                    self.restart_timer(cursor, 0);
+                }
+                return finished;
+            }
+            cursor.animation_tick = position;
+            // Frames without a timing would never run out of time to catch up on
+            if !self.show_frame(cursor, &mut sprite) {
+                break;
+            }
+        }
         Some(())
     }
 
+    /// Puts the frame the cursor is on on the sprite and times it. Returns `false` if the frame has no timing
+    fn show_frame(&self, cursor: &mut AnimationCursor, sprite: &mut Mut<Sprite>) -> bool {
+        let position = cursor.animation_tick;
+        sprite
+            .texture_atlas
+            .as_mut()
+            .expect("Fatal animation error! No texture_atlas set for entity")
+            .index = self.animation_frames[position];
+        cursor.shown_frames.push(position);
+        cursor.frame_shown = true;
+        cursor.progress.frame_shown();
+        self.time_frame(cursor, position)
+    }
+
     #[allow(unused)]
-    pub fn reset_animation(&mut self, mut sprite: Option<Mut<Sprite>>) {
-        self.animation_tick = 1;
-        let new_dur = Duration::from_secs_f32(
-            *self
-                .frame_timings_in_secs
-                .first()
-                .expect("Error With Animation Timing"),
-        );
-        self.animation_timer.set_duration(new_dur);
-        self.animation_timer.reset();
+    pub fn reset_animation(&self, cursor: &mut AnimationCursor, mut sprite: Option<Mut<Sprite>>) {
+        cursor.animation_tick = 0;
+        cursor.progress = LoopProgress::default();
+        self.restart_timer(cursor, 0);
+        // Cycling the animation puts the first frame on the sprite again and sends its markers
+        cursor.frame_shown = false;
         let x_index = self
-            .get_x_index()
+            .get_x_index(cursor)
             .expect("Something Went Wrong Reseting Animation");
         if let Some(mut sprite) = sprite {
             sprite
@@ -610,30 +1091,27 @@ impl LinearTimedAnimation {
 /// fn init_animation(
 ///     mut animations: ResMut<Animations>,
 ///     mut commands: Commands,
-///     asset_server: ResMut<AssetServer>,
-///     mut texture_atlases: ResMut<Assets<TextureAtlas>>,
+///     asset_server: Res<AssetServer>,
+///     mut layouts: ResMut<Assets<TextureAtlasLayout>>,
 /// ) {
-///     let asset = asset_server.load("path/to/your/sprite_sheet");
+///     let image = asset_server.load("path/to/your/sprite_sheet.png");
 ///
-///     let texture_atlas = TextureAtlas::from_grid(asset, Vec2::new(16.0, 16.0), 10, 1, None, None);
-///
-///     let texture_atlas_handle = texture_atlases.add(texture_atlas);
+///     let layout = layouts.add(TextureAtlasLayout::from_grid(UVec2::new(16, 16), 10, 1, None, None));
 ///
 ///     let entity = commands
-///         .spawn(AnimationDirection::default())
-///         .insert(SpriteSheetBundle {
-///             texture_atlas: texture_atlas_handle.clone(),
-///             ..Default::default()
-///         })
+///         .spawn((
+///             Animator::default(),
+///             Sprite::from_atlas_image(image.clone(), TextureAtlas::from(layout.clone())),
+///         ))
 ///         .id();
 ///     animations.insert_animation(
 ///         NewAnimation {
-///             handle: texture_atlas_handle.clone(), /* the handle for the TextureAtlas */
+///             handles: Handles::new(image, layout), /* the sprite sheet and its layout */
 ///             animation: AnimationType::LinearTransform(
 ///                 LinearTransformAnimation::new(
 ///                     Vec::from(PLAYER_RUNNING_FRAMES), /* animation_frames */
 ///                     PLAYER_METERS_PER_FRAME,          /* meters_per_frame */
-///                     true,                             /* repeating */
+///                     LoopMode::Loop,                   /* loop_mode */
 ///                 ),
 ///                 "player_running", /* AnimationName */
 ///             ),
@@ -644,28 +1122,91 @@ impl LinearTimedAnimation {
 /// ```
 #[derive(Debug, Default, Clone)]
 pub struct LinearTransformAnimation {
-    animation_tick: usize,
-    previous_transform: Transform,
     pub animation_frames: Vec<usize>,
     pub meters_per_frame: f32,
-    pub repeating: bool,
+    pub loop_mode: LoopMode,
+    /// The position in `animation_frames` shown when the animation is stopped with a [StopAnimation]
+    pub rest_frame: usize,
 }
 
 #[allow(unused)]
 impl LinearTransformAnimation {
-    fn new(animation_frames: Vec<usize>, meters_per_frame: f32, repeating: bool) -> Self {
+    pub fn new(
+        animation_frames: Vec<usize>,
+        meters_per_frame: f32,
+        loop_mode: impl Into<LoopMode>,
+    ) -> Self {
         Self {
-            animation_tick: 1,
-            previous_transform: Transform::from_xyz(0., 0., 0.),
             animation_frames,
             meters_per_frame,
-            repeating,
+            loop_mode: loop_mode.into(),
+            rest_frame: 0,
         }
     }
 
-    fn ready_to_animate(&self, transform: &Mut<Transform>, pixels_per_meter: f32) -> bool {
-        let x_diff = (transform.translation.x - self.previous_transform.translation.x).abs();
-        let y_diff = (transform.translation.y - self.previous_transform.translation.y).abs();
+    /// Sets the position in `animation_frames` shown when the animation is stopped with a [StopAnimation]
+    pub fn with_rest_frame(mut self, rest_frame: usize) -> Self {
+        self.rest_frame = rest_frame;
+        self
+    }
+
+    /// A cursor on the first frame of the animation for an entity to play it with
+    pub fn cursor(&self) -> AnimationCursor {
+        AnimationCursor::default()
+    }
+
+    /// Moves `cursor` to the frame at `to`, which is the next frame shown so the animation plays from there like it does from its first frame
+    ///
+    /// Returns the atlas index of the frame, see [SeekAnimation]
+    pub fn seek(
+        &self,
+        cursor: &mut AnimationCursor,
+        to: AnimationSeek,
+        direction: &AnimationDirection,
+    ) -> usize {
+        let position = to.position(self.animation_frames.len(), None);
+        cursor.progress = LoopProgress::default();
+        cursor.animation_tick = position;
+        self.sprite_index(cursor, direction)
+    }
+
+    /// A copy of this animation that only plays the frames of `slice`
+    ///
+    /// Returns [Result<Self, String>] an [Err(String)] if the frames of `slice` don't exist
+    pub fn slice(&self, slice: &AnimationSlice) -> Result<Self, String> {
+        let positions = slice.positions(self.animation_frames.len())?;
+        Ok(Self::new(
+            pick(&self.animation_frames, &positions),
+            self.meters_per_frame,
+            slice.loop_mode.unwrap_or(self.loop_mode),
+        ))
+    }
+
+    /// Fits the `cursor` of an entity playing `previous`, the definition this one replaced, to this one
+    ///
+    /// The current frame is kept and so are the loops played so far unless the [LoopMode] changed.
+    /// If the current frame doesn't exist in this definition the animation starts over
+    pub fn reload_cursor(&self, previous: &Self, cursor: &mut AnimationCursor) {
+        if kept_position(cursor.animation_tick, 0, self.animation_frames.len()).is_none() {
+            *cursor = AnimationCursor {
+                previous_transform: cursor.previous_transform,
+                ..self.cursor()
+            };
+            return;
+        }
+        if self.loop_mode != previous.loop_mode {
+            cursor.progress = LoopProgress::default();
+        }
+    }
+
+    fn ready_to_animate(
+        &self,
+        cursor: &AnimationCursor,
+        transform: &Mut<Transform>,
+        pixels_per_meter: f32,
+    ) -> bool {
+        let x_diff = (transform.translation.x - cursor.previous_transform.translation.x).abs();
+        let y_diff = (transform.translation.y - cursor.previous_transform.translation.y).abs();
 
         let modifier = pixels_per_meter * self.meters_per_frame;
 
@@ -675,34 +1216,40 @@ impl LinearTransformAnimation {
         false
     }
 
-    pub fn sprite_index(&mut self, _direction: &AnimationDirection) -> usize {
-        self.get_x_index().unwrap_or_default()
+    pub fn sprite_index(
+        &self,
+        cursor: &mut AnimationCursor,
+        _direction: &AnimationDirection,
+    ) -> usize {
+        self.get_x_index(cursor).unwrap_or_default()
     }
 
-    fn get_x_index(&mut self) -> Option<usize> {
-        match self.animation_frames.get(self.animation_tick) {
-            Some(index) => Some(*index),
-            None => {
-                self.animation_tick = 1;
-                if self.repeating {
-                    return Some(0);
-                }
-                None
-            }
+    fn get_x_index(&self, cursor: &AnimationCursor) -> Option<usize> {
+        if cursor.progress.ended() {
+            return None;
         }
+        self.animation_frames.get(cursor.animation_tick).copied()
     }
 
     pub fn cycle_animation(
-        &mut self,
+        &self,
+        cursor: &mut AnimationCursor,
         mut sprite: Mut<Sprite>,
         transform: Mut<Transform>,
         pixels_per_meter: f32,
     ) -> Option<()> {
-        if self.ready_to_animate(&transform, pixels_per_meter) {
-            self.previous_transform = *transform;
-            let x_index = self.get_x_index()?;
-
-            let x_index = self.get_x_index()?;
+        if self.ready_to_animate(cursor, &transform, pixels_per_meter) {
+            cursor.previous_transform = *transform;
+            let x_index = match self.get_x_index(cursor) {
+                Some(index) => index,
+                None => {
+                    let finished = cursor.progress.finish(self.loop_mode);
+                    if !cursor.progress.is_holding() {
+                        cursor.animation_tick = 0;
+                    }
+                    return finished;
+                }
+            };
 
             sprite
                 .texture_atlas
@@ -710,19 +1257,25 @@ impl LinearTransformAnimation {
                 .expect("Fatal animation error! No texture_atlas set for entity")
                 .index = x_index;
 
-            self.animation_tick += 1;
+            cursor.progress.frame_shown();
+            cursor.animation_tick = cursor.progress.advance(
+                self.loop_mode,
+                cursor.animation_tick,
+                self.animation_frames.len(),
+            );
             return Some(());
         }
         Some(())
     }
 
     #[allow(unused)]
-    pub fn reset_animation(&mut self, mut sprite: Option<Mut<Sprite>>) {
-        self.animation_tick = 1;
+    pub fn reset_animation(&self, cursor: &mut AnimationCursor, mut sprite: Option<Mut<Sprite>>) {
+        cursor.animation_tick = 0;
+        cursor.progress = LoopProgress::default();
         if let Some(mut sprite) = sprite {
             if let Some(mut texture_atlas) = sprite.texture_atlas.as_mut() {
                 let x_index = self
-                    .get_x_index()
+                    .get_x_index(cursor)
                     .expect("Something Went Wrong Reseting Animation");
                 texture_atlas.index = x_index
             }
@@ -737,31 +1290,28 @@ impl LinearTransformAnimation {
 /// fn init_animation(
 ///     mut animations: ResMut<Animations>,
 ///     mut commands: Commands,
-///     asset_server: ResMut<AssetServer>,
-///     mut texture_atlases: ResMut<Assets<TextureAtlas>>,
+///     asset_server: Res<AssetServer>,
+///     mut layouts: ResMut<Assets<TextureAtlasLayout>>,
 /// ) {
-///     let asset = asset_server.load("path/to/your/sprite_sheet");
+///     let image = asset_server.load("path/to/your/sprite_sheet.png");
 ///
-///     let texture_atlas = TextureAtlas::from_grid(asset, Vec2::new(16.0, 16.0), 10, 1, None, None);
-///
-///     let texture_atlas_handle = texture_atlases.add(texture_atlas);
+///     let layout = layouts.add(TextureAtlasLayout::from_grid(UVec2::new(16, 16), 10, 1, None, None));
 ///
 ///     let entity = commands
-///         .spawn(AnimationDirection::default())
-///         .insert(SpriteSheetBundle {
-///             texture_atlas: texture_atlas_handle.clone(),
-///             ..Default::default()
-///         })
+///         .spawn((
+///             Animator::default(),
+///             Sprite::from_atlas_image(image.clone(), TextureAtlas::from(layout.clone())),
+///         ))
 ///         .id();
 ///     animations.insert_animation(
 ///         NewAnimation {
-///             handle: texture_atlas_handle.clone(), /* the handle for the TextureAtlas */
+///             handles: Handles::new(image, layout), /* the sprite sheet and its layout */
 ///             animation: AnimationType::SingleFrame(
 ///                 SingleFrameAnimation::new(
 ///                     0, /* x_index_pos */
-///                     AnimationDirectionIndexes::IndexBased(FlipBased {
+///                     AnimationDirectionIndexes::FlipBased(FlipBasedDirection {
 ///                         /* direction_indexes */
-///                         x_direction_is_flipped: true,
+///                         left_direction_is_flipped: true,
 ///                         x_direction_index: 0,
 ///                     }),
 ///                     true,              /* blocking */
@@ -780,11 +1330,13 @@ impl LinearTransformAnimation {
 pub struct SingleFrameAnimation {
     pub blocking: bool,
     pub blocking_priority: i32,
-    pub blocking_timer: AnimationTimer,
-    pub blocking_finished: bool,
-    pub frame: Vec2,
+    /// How long the animation blocks for once it's shown
+    pub blocking_duration_in_sec: f32,
+    /// The clock the blocking timer runs on. [None] uses the one set on the [AnimationsPlugin]
+    pub time_source: Option<AnimationTimeSource>,
+    pub frame: SheetGrid,
+    pub sheet_indexing: SheetIndexer,
     x_index_pos: usize,
-    previous_dir_index: usize,
     direction_indexes: AnimationDirectionIndexes,
 }
 
@@ -795,75 +1347,83 @@ impl SingleFrameAnimation {
         blocking: bool,
         blocking_priority: i32,
         blocking_duration_in_sec: f32,
-        frame: Vec2,
+        frame: impl Into<SheetGrid>,
     ) -> Self {
         Self {
             blocking,
             blocking_priority,
-            blocking_timer: AnimationTimer(Timer::from_seconds(
-                blocking_duration_in_sec,
-                TimerMode::Repeating,
-            )),
+            blocking_duration_in_sec,
             direction_indexes,
-            frame,
+            frame: frame.into(),
             x_index_pos,
             ..Default::default()
         }
     }
+
+    /// Sets how the frames are laid out in the sprite sheet. Defaults to [RowMajor]
+    pub fn with_sheet_indexing(mut self, sheet_indexing: impl SheetIndexing) -> Self {
+        self.sheet_indexing = SheetIndexer::new(sheet_indexing);
+        self
+    }
+
+    /// Times the animation with `time_source` instead of the one set on the [AnimationsPlugin]
+    pub fn with_time_source(mut self, time_source: AnimationTimeSource) -> Self {
+        self.time_source = Some(time_source);
+        self
+    }
+
+    /// A cursor with the blocking timer at the start for an entity to play the animation with
+    pub fn cursor(&self) -> AnimationCursor {
+        AnimationCursor {
+            animation_timer: AnimationTimer(Timer::from_seconds(
+                self.blocking_duration_in_sec,
+                TimerMode::Repeating,
+            )),
+            ..Default::default()
+        }
+    }
+
+    /// Fits the `cursor` of an entity playing the previous definition of this animation to this one, keeping the blocking state
+    pub fn reload_cursor(&self, cursor: &mut AnimationCursor) {
+        cursor
+            .animation_timer
+            .set_duration(Duration::from_secs_f32(self.blocking_duration_in_sec));
+    }
     // pub get_y_index(&self, )
     pub fn cycle_animation(
-        &mut self,
+        &self,
+        cursor: &mut AnimationCursor,
         mut sprite: Mut<Sprite>,
         direction: &AnimationDirection,
         delta: Duration,
     ) {
-        if !self.blocking_timer.duration().is_zero() {
-            self.blocking_timer.tick(delta);
+        if !cursor.animation_timer.duration().is_zero() {
+            cursor.animation_timer.tick(delta);
         }
-        if self.blocking_timer.is_finished() {
-            self.blocking_finished = true;
+        if cursor.animation_timer.is_finished() {
+            cursor.blocking_finished = true;
         }
 
-        let index;
         if *direction != AnimationDirection::Still {
-            self.previous_dir_index = AnimationDirection::get_direction(direction).y as usize;
+            if let Some(row) = self.direction_row(cursor, direction) {
+                cursor.previous_dir_index = row;
+            }
         }
-        match self.direction_indexes {
-            AnimationDirectionIndexes::IndexBased(index_based_direction) => match *direction {
-                AnimationDirection::Still => {
-                    index = self.frame.x as usize * self.previous_dir_index + self.x_index_pos
-                }
-                AnimationDirection::Down => {
-                    index = self.frame.x as usize * index_based_direction.down + self.x_index_pos
-                }
-                AnimationDirection::Left => {
-                    index = self.frame.x as usize * index_based_direction.left + self.x_index_pos
-                }
-                AnimationDirection::Right => {
-                    index = self.frame.x as usize * index_based_direction.right + self.x_index_pos
-                }
-                AnimationDirection::Up => {
-                    index = self.frame.x as usize * index_based_direction.up + self.x_index_pos
+        let index = self.sprite_index(cursor, direction);
+        if let AnimationDirectionIndexes::FlipBased(flip_based_direction) = self.direction_indexes {
+            if flip_based_direction.left_direction_is_flipped {
+                match *direction {
+                    AnimationDirection::Left => sprite.flip_x = true,
+                    AnimationDirection::Right => sprite.flip_x = false,
+                    _ => {}
                 }
-            },
-            AnimationDirectionIndexes::FlipBased(flip_based_direction) => {
-                index = self.frame.x as usize * flip_based_direction.x_direction_index
-                    + self.x_index_pos;
-                if flip_based_direction.left_direction_is_flipped {
-                    match *direction {
-                        AnimationDirection::Left => sprite.flip_x = true,
-                        AnimationDirection::Right => sprite.flip_x = false,
-                        _ => {}
-                    }
-                } else {
-                    match *direction {
-                        AnimationDirection::Left => sprite.flip_x = false,
-                        AnimationDirection::Right => sprite.flip_x = true,
-                        _ => {}
-                    }
+            } else {
+                match *direction {
+                    AnimationDirection::Left => sprite.flip_x = false,
+                    AnimationDirection::Right => sprite.flip_x = true,
+                    _ => {}
                 }
             }
-            AnimationDirectionIndexes::FX(fx_based_animation) => index = fx_based_animation.index,
         }
         sprite
             .texture_atlas
@@ -872,39 +1432,49 @@ impl SingleFrameAnimation {
             .index = index;
     }
 
-    pub fn sprite_index(&self, direction: &AnimationDirection) -> usize {
-        match self.direction_indexes {
-            AnimationDirectionIndexes::IndexBased(index_based_direction) => match *direction {
-                AnimationDirection::Still => {
-                    self.frame.x as usize * self.previous_dir_index + self.x_index_pos
-                }
-                AnimationDirection::Down => {
-                    self.frame.x as usize * index_based_direction.down + self.x_index_pos
-                }
-                AnimationDirection::Left => {
-                    self.frame.x as usize * index_based_direction.left + self.x_index_pos
-                }
-                AnimationDirection::Right => {
-                    self.frame.x as usize * index_based_direction.right + self.x_index_pos
-                }
-                AnimationDirection::Up => {
-                    self.frame.x as usize * index_based_direction.up + self.x_index_pos
-                }
+    pub fn sprite_index(&self, cursor: &AnimationCursor, direction: &AnimationDirection) -> usize {
+        match self.direction_row(cursor, direction) {
+            Some(row) => self
+                .sheet_indexing
+                .atlas_index(self.x_index_pos, row, &self.frame),
+            None => match self.direction_indexes {
+                AnimationDirectionIndexes::FX(fx_based_animation) => fx_based_animation.index,
+                _ => unreachable!(),
             },
+        }
+    }
+
+    /// The row of the sprite sheet shown facing `direction`. [None] for FX animations which use an atlas index directly
+    fn direction_row(
+        &self,
+        cursor: &AnimationCursor,
+        direction: &AnimationDirection,
+    ) -> Option<usize> {
+        match self.direction_indexes {
+            AnimationDirectionIndexes::IndexBased(index_based_direction) => {
+                Some(match *direction {
+                    AnimationDirection::Still => cursor.previous_dir_index,
+                    AnimationDirection::Down => index_based_direction.down,
+                    AnimationDirection::Left => index_based_direction.left,
+                    AnimationDirection::Right => index_based_direction.right,
+                    AnimationDirection::Up => index_based_direction.up,
+                })
+            }
             AnimationDirectionIndexes::FlipBased(flip_based_direction) => {
-                self.frame.x as usize * flip_based_direction.x_direction_index + self.x_index_pos
+                Some(flip_based_direction.x_direction_index)
             }
-            AnimationDirectionIndexes::FX(fx_based_animation) => fx_based_animation.index,
+            AnimationDirectionIndexes::FX(_) => None,
         }
     }
 
     pub fn reset_animation(
-        &mut self,
+        &self,
+        cursor: &mut AnimationCursor,
         sprite: Option<Mut<Sprite>>,
         _direction: Option<&AnimationDirection>,
     ) {
-        self.blocking_timer.reset();
-        self.blocking_finished = false;
+        cursor.animation_timer.reset();
+        cursor.blocking_finished = false;
 
         if let Some(mut sprite) = sprite {
             sprite.flip_x = false;
@@ -912,3 +1482,331 @@ impl SingleFrameAnimation {
         }
     }
 }
+
+/// The values at `positions` in order
+fn pick<T: Copy>(values: &[T], positions: &[usize]) -> Vec<T> {
+    // Timings that were left out, like the seconds of an animation timed in ticks, stay left out
+    if values.is_empty() {
+        return Vec::new();
+    }
+    positions.iter().map(|position| values[*position]).collect()
+}
+
+/// Moves the markers to the new positions of their frames. Markers on frames that aren't in `positions` are dropped
+fn slice_markers(
+    frame_markers: &[(usize, &'static str)],
+    positions: &[usize],
+) -> Vec<(usize, &'static str)> {
+    positions
+        .iter()
+        .enumerate()
+        .flat_map(|(new_position, position)| {
+            frame_markers
+                .iter()
+                .filter(move |(frame, _)| frame == position)
+                .map(move |(_, marker)| (new_position, *marker))
+        })
+        .collect()
+}
+
+/// The `animation_tick` to continue from after reloading an animation with `frame_count` frames
+/// The position in `animation_frames` of a cursor on `animation_tick`, where the first frame is `first_tick`
+///
+/// Returns [None] if the frame isn't one of the `frame_count` frames
+fn kept_position(animation_tick: usize, first_tick: usize, frame_count: usize) -> Option<usize> {
+    animation_tick
+        .checked_sub(first_tick)
+        .filter(|position| *position < frame_count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn single_frame_directions_use_sheet_indexing() {
+        let animation = SingleFrameAnimation::new(
+            2,
+            AnimationDirectionIndexes::IndexBased(IndexBasedDirection {
+                left: 1,
+                right: 2,
+                up: 3,
+                down: 0,
+            }),
+            false,
+            0,
+            0.,
+            SheetGrid::new(UVec2::splat(16), 4, 4),
+        )
+        .with_sheet_indexing(ColumnMajor);
+        let mut world = World::new();
+        let entity = world
+            .spawn(Sprite {
+                texture_atlas: Some(TextureAtlas::default()),
+                ..Default::default()
+            })
+            .id();
+        let mut cursor = animation.cursor();
+        for (direction, expected) in [
+            (AnimationDirection::Down, 8),
+            (AnimationDirection::Left, 9),
+            (AnimationDirection::Right, 10),
+            (AnimationDirection::Up, 11),
+            // Still keeps facing the last direction
+            (AnimationDirection::Still, 11),
+        ] {
+            let sprite = world.get_mut::<Sprite>(entity).unwrap();
+            animation.cycle_animation(&mut cursor, sprite, &direction, Duration::ZERO);
+            let shown = world.get::<Sprite>(entity).unwrap();
+            assert_eq!(shown.texture_atlas.as_ref().unwrap().index, expected);
+            assert_eq!(animation.sprite_index(&cursor, &direction), expected);
+        }
+    }
+
+    /// `count` half seconds, which is the timing of every frame in these tests
+    fn half_seconds(count: u64) -> AnimationDelta {
+        AnimationDelta {
+            time: Duration::from_millis(500 * count),
+            ticks: 0,
+        }
+    }
+
+    /// The atlas index shown on every cycle until a linear timed animation finishes, starting when it's started
+    fn play_linear_timed(animation: &LinearTimedAnimation) -> Vec<usize> {
+        let mut world = World::new();
+        let entity = world
+            .spawn(Sprite {
+                texture_atlas: Some(TextureAtlas::default()),
+                ..Default::default()
+            })
+            .id();
+        let mut cursor = animation.cursor();
+        let mut shown = Vec::new();
+        for step in 0..animation.animation_frames.len() + 2 {
+            let sprite = world.get_mut::<Sprite>(entity).unwrap();
+            if animation
+                .cycle_animation(&mut cursor, sprite, half_seconds(step.min(1) as u64))
+                .is_none()
+            {
+                return shown;
+            }
+            let sprite = world.get::<Sprite>(entity).unwrap();
+            shown.push(sprite.texture_atlas.as_ref().unwrap().index);
+        }
+        panic!("the animation never finished");
+    }
+
+    #[test]
+    fn linear_timed_shows_every_frame_once() {
+        let animation =
+            LinearTimedAnimation::new(vec![10, 11, 12, 13, 14], vec![0.5; 5], LoopMode::Once);
+        assert_eq!(play_linear_timed(&animation), vec![10, 11, 12, 13, 14]);
+        let closing = animation
+            .slice(&AnimationSlice::new().with_frames(2..5).reversed())
+            .unwrap();
+        assert_eq!(play_linear_timed(&closing), vec![14, 13, 12]);
+        let mut cursor = animation.cursor();
+        assert_eq!(
+            animation.sprite_index(&mut cursor, &AnimationDirection::Still),
+            10
+        );
+    }
+
+    #[test]
+    fn stopped_animations_play_from_their_rest_frame() {
+        let animation =
+            LinearTimedAnimation::new(vec![10, 11, 12, 13], vec![0.5; 4], LoopMode::Loop)
+                .with_rest_frame(1);
+        let mut world = World::new();
+        let entity = world
+            .spawn(Sprite {
+                texture_atlas: Some(TextureAtlas::default()),
+                ..Default::default()
+            })
+            .id();
+        let mut cycle = |cursor: &mut AnimationCursor, delta: AnimationDelta| {
+            let sprite = world.get_mut::<Sprite>(entity).unwrap();
+            animation.cycle_animation(cursor, sprite, delta).unwrap();
+            let sprite = world.get::<Sprite>(entity).unwrap();
+            sprite.texture_atlas.as_ref().unwrap().index
+        };
+        let mut cursor = animation.cursor();
+        let shown: Vec<_> = (0..3)
+            .map(|step| cycle(&mut cursor, half_seconds(step.min(1))))
+            .collect();
+        assert_eq!(shown, vec![10, 11, 12]);
+
+        // The stop shows the rest frame, which then gets its whole timing
+        let stopped = AnimationType::LinearTimed(animation.clone(), "walk");
+        assert_eq!(
+            stopped.stop(&mut cursor, &AnimationDirection::Still),
+            Some(11)
+        );
+        let shown: Vec<_> = (0..3)
+            .map(|_| cycle(&mut cursor, half_seconds(1)))
+            .collect();
+        assert_eq!(shown, vec![12, 13, 10]);
+    }
+
+    #[test]
+    fn linear_transform_shows_every_frame_once() {
+        let animation = LinearTransformAnimation::new(vec![10, 11, 12], 1., LoopMode::Once);
+        let mut world = World::new();
+        world.spawn((
+            Sprite {
+                texture_atlas: Some(TextureAtlas::default()),
+                ..Default::default()
+            },
+            Transform::default(),
+        ));
+        let mut query = world.query::<(&mut Sprite, &mut Transform)>();
+        let mut cursor = animation.cursor();
+        let mut shown = Vec::new();
+        for step in 1..=4 {
+            let (sprite, mut transform) = query.single_mut(&mut world).unwrap();
+            transform.translation.x = step as f32;
+            if animation
+                .cycle_animation(&mut cursor, sprite, transform, 1.)
+                .is_none()
+            {
+                break;
+            }
+            let (sprite, _) = query.single(&world).unwrap();
+            shown.push(sprite.texture_atlas.as_ref().unwrap().index);
+        }
+        assert_eq!(shown, vec![10, 11, 12]);
+    }
+
+    #[test]
+    fn looping_linear_animations_start_over_from_their_first_frame() {
+        // Tiled tile animations hardly ever start at atlas index 0
+        let animation = LinearTimedAnimation::new(vec![4, 5, 6], vec![0.5; 3], LoopMode::Loop);
+        let mut world = World::new();
+        world.spawn((
+            Sprite {
+                texture_atlas: Some(TextureAtlas::default()),
+                ..Default::default()
+            },
+            Transform::default(),
+        ));
+        let mut query = world.query::<(&mut Sprite, &mut Transform)>();
+        let mut cursor = animation.cursor();
+        let mut shown = Vec::new();
+        for step in 0..7 {
+            let (sprite, _) = query.single_mut(&mut world).unwrap();
+            animation
+                .cycle_animation(&mut cursor, sprite, half_seconds(step.min(1)))
+                .unwrap();
+            let (sprite, _) = query.single(&world).unwrap();
+            shown.push(sprite.texture_atlas.as_ref().unwrap().index);
+        }
+        assert_eq!(shown, vec![4, 5, 6, 4, 5, 6, 4]);
+
+        let animation = LinearTransformAnimation::new(vec![4, 5, 6], 1., LoopMode::Loop);
+        let mut cursor = animation.cursor();
+        let mut shown = Vec::new();
+        for step in 1..=7 {
+            let (sprite, mut transform) = query.single_mut(&mut world).unwrap();
+            transform.translation.x = step as f32;
+            animation
+                .cycle_animation(&mut cursor, sprite, transform, 1.)
+                .unwrap();
+            let (sprite, _) = query.single(&world).unwrap();
+            shown.push(sprite.texture_atlas.as_ref().unwrap().index);
+        }
+        assert_eq!(shown, vec![4, 5, 6, 4, 5, 6, 4]);
+    }
+
+    #[test]
+    fn timed_frames_get_their_whole_timing_from_the_start() {
+        let animation = TimedAnimation::new(
+            vec![0, 1, 2, 3],
+            vec![0.5; 4],
+            SheetGrid::new(UVec2::splat(16), 4, 1),
+            AnimationDirectionIndexes::FX(FXBasedDirection { index: 0 }),
+            LoopMode::Once,
+            false,
+            0,
+        );
+        let mut world = World::new();
+        let entity = world
+            .spawn(Sprite {
+                texture_atlas: Some(TextureAtlas::default()),
+                ..Default::default()
+            })
+            .id();
+        let mut cursor = animation.cursor();
+        let mut cycle = |cursor: &mut AnimationCursor, delta: AnimationDelta| {
+            let sprite = world.get_mut::<Sprite>(entity).unwrap();
+            let cycled =
+                animation.cycle_animation(cursor, sprite, &AnimationDirection::Still, delta);
+            let sprite = world.get::<Sprite>(entity).unwrap();
+            (cycled, sprite.texture_atlas.as_ref().unwrap().index)
+        };
+
+        // The time that passed before the animation started is left out
+        assert_eq!(cycle(&mut cursor, half_seconds(3)), (Some(()), 0));
+        assert_eq!(cursor.take_shown_frames(), [0]);
+        // Crossing two frames at once shows both of them
+        assert_eq!(cycle(&mut cursor, half_seconds(2)), (Some(()), 2));
+        assert_eq!(cursor.take_shown_frames(), [1, 2]);
+        assert_eq!(cycle(&mut cursor, half_seconds(1)), (Some(()), 3));
+        assert_eq!(cycle(&mut cursor, half_seconds(1)), (None, 3));
+        assert_eq!(cursor.take_shown_frames(), [3]);
+    }
+
+    #[test]
+    fn tick_timed_animations_need_no_seconds() {
+        let animation = TimedAnimation::new(
+            vec![0, 1, 2],
+            Vec::new(),
+            SheetGrid::new(UVec2::splat(16), 3, 1),
+            AnimationDirectionIndexes::FX(FXBasedDirection { index: 0 }),
+            LoopMode::Once,
+            false,
+            0,
+        )
+        .with_tick_timings(vec![2, 1, 1]);
+        let mut world = World::new();
+        let entity = world
+            .spawn(Sprite {
+                texture_atlas: Some(TextureAtlas::default()),
+                ..Default::default()
+            })
+            .id();
+        let mut cycle = |animation: &TimedAnimation, cursor: &mut AnimationCursor, ticks| {
+            let sprite = world.get_mut::<Sprite>(entity).unwrap();
+            let delta = AnimationDelta {
+                time: Duration::ZERO,
+                ticks,
+            };
+            animation.cycle_animation(cursor, sprite, &AnimationDirection::Still, delta);
+            let sprite = world.get::<Sprite>(entity).unwrap();
+            sprite.texture_atlas.as_ref().unwrap().index
+        };
+        let mut cursor = animation.cursor();
+        let shown: Vec<_> = [0, 1, 1, 1]
+            .into_iter()
+            .map(|ticks| cycle(&animation, &mut cursor, ticks))
+            .collect();
+        assert_eq!(shown, [0, 0, 1, 2]);
+
+        let sliced = animation
+            .slice(&AnimationSlice::new().with_frames(1..3))
+            .unwrap();
+        assert!(sliced.frame_timings_in_secs.is_empty());
+        let mut cursor = sliced.cursor();
+        let shown: Vec<_> = [0, 1]
+            .into_iter()
+            .map(|ticks| cycle(&sliced, &mut cursor, ticks))
+            .collect();
+        assert_eq!(shown, [1, 2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "one tick timing for each frame")]
+    fn tick_timings_need_one_timing_per_frame() {
+        LinearTimedAnimation::new(vec![0, 1, 2], vec![0.1; 3], LoopMode::Loop)
+            .with_tick_timings(vec![6, 6]);
+    }
+}
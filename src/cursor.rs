@@ -0,0 +1,41 @@
+use crate::*;
+
+/// Where an entity is in the animation it's playing
+///
+/// Animations are shared definitions that never change while playing, so every entity keeps its own cursor
+/// in its [AnimationPlayback] and any number of entities can play the same animation at once
+#[derive(Debug, Clone, Default)]
+pub struct AnimationCursor {
+    pub(crate) animation_tick: usize,
+    pub(crate) previous_dir_index: usize,
+    pub(crate) previous_transform: Transform,
+    pub(crate) progress: LoopProgress,
+    pub(crate) animation_timer: AnimationTimer,
+    pub(crate) tick_timer: TickTimer,
+    pub(crate) shown_frames: Vec<usize>,
+    /// If the current frame is on the sprite. A new cursor puts its first frame on the sprite the first time it's cycled
+    pub(crate) frame_shown: bool,
+    pub(crate) blocking_finished: bool,
+}
+
+impl AnimationCursor {
+    /// If a blocking [SingleFrameAnimation] has been shown for its whole `blocking_duration_in_sec`
+    pub fn blocking_finished(&self) -> bool {
+        self.blocking_finished
+    }
+
+    /// Takes the positions in `animation_frames` of the frames the last `cycle_animation` showed
+    pub(crate) fn take_shown_frames(&mut self) -> Vec<usize> {
+        std::mem::take(&mut self.shown_frames)
+    }
+
+    /// Takes if the animation started over since the last time this was called
+    pub(crate) fn take_looped(&mut self) -> bool {
+        self.progress.take_looped()
+    }
+
+    /// If the animation finished and is staying on its last frame because of [LoopMode::HoldLastFrame]
+    pub(crate) fn is_holding(&self) -> bool {
+        self.progress.is_holding()
+    }
+}
@@ -0,0 +1,212 @@
+// Everything in here is deprecated and still has to refer to itself
+#![allow(deprecated)]
+
+use std::sync::atomic::Ordering;
+
+use crate::*;
+
+/// The state of an animating entity, which is now its own component
+///
+/// **Note** this breaks source compatibility. The fields of [AnimationPlayback] aren't public, use its methods instead
+#[deprecated(note = "use the `AnimationPlayback` component of the entity instead")]
+pub type AnimatingEntity = AnimationPlayback;
+
+/// A change to an entity made through one of the deprecated methods of [Animations]
+#[derive(Debug, Clone)]
+pub(crate) enum PlaybackControl {
+    Pause,
+    Resume,
+    Stop,
+    Seek(AnimationSeek),
+}
+
+impl PlaybackControl {
+    pub(crate) fn apply(&self, playback: &mut AnimationPlayback) {
+        match self {
+            PlaybackControl::Pause => playback.pause(),
+            PlaybackControl::Resume => playback.resume(),
+            PlaybackControl::Stop => playback.stop(),
+            PlaybackControl::Seek(to) => playback.seek(*to),
+        }
+    }
+}
+
+/// The entity methods [Animations] had before every entity got its own [AnimationPlayback]
+///
+/// They read a copy of every [AnimationPlayback] taken at the end of each update, which is only kept once one
+/// of them has been called. So the first call doesn't see any entity until the next update.
+///
+/// `get_entity` and `get_mut_map` are gone since changing a copy would undo everything that happened to the entity since.
+/// Query the [AnimationPlayback] of the entity to change it instead
+impl Animations {
+    /// Starts keeping the copy of every [AnimationPlayback] the deprecated methods read from
+    fn mirror_entities(&self) {
+        self.mirror_entities.store(true, Ordering::Relaxed);
+    }
+
+    /// Queues `control` for the entity and applies it to its copy straight away
+    fn control_entity(&mut self, entity: Entity, control: PlaybackControl) -> Result<(), String> {
+        self.mirror_entities();
+        if let Some(playback) = self.entities.get_mut(&entity) {
+            control.apply(playback);
+        }
+        self.playback_changes
+            .push(PlaybackChange::Control { entity, control });
+        Ok(())
+    }
+
+    /// Add an [Entity] to the pool without a current animation specified
+    ///
+    /// Returns [Result<(), String>] an [Err(String)] if the entity already exists in the pool
+    #[deprecated(note = "entities with an `Animator` get an `AnimationPlayback` on their own")]
+    pub fn insert_entity(&mut self, entity: Entity) -> Result<(), String> {
+        self.mirror_entities();
+        if self.entities.contains_key(&entity) {
+            return Err(format!(
+                "Entity {:?} already exists in `Animations`",
+                entity
+            ));
+        }
+        self.playback_changes
+            .push(PlaybackChange::Insert { entity });
+        Ok(())
+    }
+
+    /// Checks if the animation specified is not animating on the entity specified currently
+    ///
+    /// Returns [None] if the entity does not exist in the pool
+    #[deprecated(note = "use `AnimationPlayback::is_new_animation` instead")]
+    pub fn is_new_animation(&self, animation_name: AnimationName, entity: &Entity) -> Option<bool> {
+        self.mirror_entities();
+        self.entities
+            .get(entity)
+            .map(|playback| playback.is_new_animation(animation_name))
+    }
+
+    /// If the entity specified exists in the pool
+    #[deprecated(note = "check if the entity has an `AnimationPlayback` instead")]
+    pub fn has_entity(&self, entity: &Entity) -> bool {
+        self.mirror_entities();
+        self.entities.contains_key(entity)
+    }
+
+    /// Returns [Some(())] if the animation already exists on the entity specified
+    ///
+    /// Returns [None] if the entity was not found in the pool
+    ///
+    /// Returns [None] if the animation was not found on the entity specified
+    #[deprecated(note = "use `AnimationPlayback::has_animation` instead")]
+    pub fn entity_has_animation(
+        &self,
+        animation_name: &AnimationName,
+        entity: Entity,
+    ) -> Option<()> {
+        self.mirror_entities();
+        self.entities
+            .get(&entity)
+            .filter(|playback| playback.has_animation(animation_name))
+            .map(|_| ())
+    }
+
+    /// Returns [Some(bool)] if the entity exists and [Some(true)] if the entity is in a blocking animation
+    ///
+    /// Returns [None] if the entity was not found
+    #[deprecated(note = "use `AnimationPlayback::in_blocking_animation` instead")]
+    pub fn in_blocking_animation(&self, entity: Entity) -> Option<bool> {
+        self.mirror_entities();
+        self.entities
+            .get(&entity)
+            .map(AnimationPlayback::in_blocking_animation)
+    }
+
+    /// Returns [Some(bool)] if the entity exists and [Some(true)] if the entity is in an animation
+    ///
+    /// Returns [None] if the entity was not found
+    #[deprecated(note = "use `AnimationPlayback::in_animation` instead")]
+    pub fn in_animation(&self, entity: Entity) -> Option<bool> {
+        self.mirror_entities();
+        self.entities
+            .get(&entity)
+            .map(AnimationPlayback::in_animation)
+    }
+
+    /// Returns [Some(bool)] if the entity exists and [Some(true)] if the entity is in the animation specified
+    ///
+    /// Returns [None] if the entity was not found
+    #[deprecated(note = "use `AnimationPlayback::doing_animation` instead")]
+    pub fn doing_animation(&self, entity: Entity, animation_name: AnimationName) -> Option<bool> {
+        self.mirror_entities();
+        self.entities
+            .get(&entity)
+            .map(|playback| playback.doing_animation(animation_name))
+    }
+
+    /// Freezes the current animation of the entity specified on its current frame
+    ///
+    /// The entity is paused at the start of the next [AnimationSystems::Input]
+    #[deprecated(note = "use `AnimationPlayback::pause` or send a `PauseAnimation` instead")]
+    pub fn pause_animation(&mut self, entity: Entity) -> Result<(), String> {
+        self.control_entity(entity, PlaybackControl::Pause)
+    }
+
+    /// Continues the current animation of the entity specified after [Animations::pause_animation]
+    ///
+    /// The entity is resumed at the start of the next [AnimationSystems::Input]
+    #[deprecated(note = "use `AnimationPlayback::resume` or send a `ResumeAnimation` instead")]
+    pub fn resume_animation(&mut self, entity: Entity) -> Result<(), String> {
+        self.control_entity(entity, PlaybackControl::Resume)
+    }
+
+    /// Returns [Some(bool)] if the entity exists and [Some(true)] if its animation is paused
+    ///
+    /// Returns [None] if the entity was not found
+    #[deprecated(note = "use `AnimationPlayback::is_paused` instead")]
+    pub fn is_paused(&self, entity: Entity) -> Option<bool> {
+        self.mirror_entities();
+        self.entities.get(&entity).map(AnimationPlayback::is_paused)
+    }
+
+    /// Stops the current animation of the entity specified and moves it back to its rest frame
+    ///
+    /// The entity is stopped at the start of the next [AnimationSystems::Input]
+    #[deprecated(note = "use `AnimationPlayback::stop` or send a `StopAnimation` instead")]
+    pub fn stop_animation(&mut self, entity: Entity) -> Result<(), String> {
+        self.control_entity(entity, PlaybackControl::Stop)
+    }
+
+    /// Moves the current animation of the entity specified to the frame at `to`
+    ///
+    /// The entity is moved at the start of the next [AnimationSystems::Input]
+    #[deprecated(note = "use `AnimationPlayback::seek` or send a `SeekAnimation` instead")]
+    pub fn seek_animation(&mut self, entity: Entity, to: AnimationSeek) -> Result<(), String> {
+        self.control_entity(entity, PlaybackControl::Seek(to))
+    }
+
+    /// Returns `true` if the [Entity] exists in the [Animations] map
+    #[deprecated(note = "check if the entity has an `AnimationPlayback` instead")]
+    pub fn is_inserted(&self, key: &Entity) -> bool {
+        self.mirror_entities();
+        self.entities.contains_key(key)
+    }
+
+    /// Mainly for debug purposes to see the map
+    #[deprecated(note = "query `AnimationPlayback` instead")]
+    pub fn get_map(&self) -> &HashMap<Entity, AnimationPlayback> {
+        self.mirror_entities();
+        &self.entities
+    }
+}
+
+/// Copies every [AnimationPlayback] into [Animations] for the deprecated entity methods once one of them was called
+pub(crate) fn mirror_animation_playback(
+    query: Query<(Entity, &AnimationPlayback)>,
+    mut animations: ResMut<Animations>,
+) {
+    if !animations.mirror_entities.load(Ordering::Relaxed) {
+        return;
+    }
+    animations.entities = query
+        .iter()
+        .map(|(entity, playback)| (entity, playback.clone()))
+        .collect();
+}
@@ -0,0 +1,246 @@
+use bevy::asset::{io::Reader, AssetLoader, LoadContext};
+
+use super::{SheetFrames, SheetRect, SheetSize, SheetTrim};
+use crate::*;
+
+/// Loads the JSON written by Aseprite's `--data` export (`.aseprite.json`) as an [AnimationLibrary]
+///
+/// Every frame tag becomes a [TimedAnimation] named after the tag with its timings taken from the frame durations.
+/// If the sheet has no tags a single animation named after the file is created from every frame.
+///
+/// Both the "Array" and "Hash" frame formats are supported
+///
+/// **Note** tags with a `repeat` count play that many times using [LoopMode::LoopCount], every other tag loops forever
+#[derive(Debug, Default, TypePath)]
+pub struct AsepriteLoader;
+
+impl AssetLoader for AsepriteLoader {
+    type Asset = AnimationLibrary;
+    type Settings = ();
+    type Error = AnimationLibraryError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<AnimationLibrary, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let sheet: AsepriteSheet = serde_json::from_slice(&bytes)?;
+
+        let image_path = load_context.path().resolve_embed(&sheet.meta.image)?;
+        let image = load_context.load::<Image>(image_path);
+        let mut layout =
+            TextureAtlasLayout::new_empty(UVec2::new(sheet.meta.size.w, sheet.meta.size.h));
+        let mut pivots = Vec::with_capacity(sheet.frames.0.len());
+        for (_, frame) in sheet.frames.0.iter() {
+            layout.add_texture(frame.frame.urect());
+            pivots.push(
+                frame
+                    .trim
+                    .map(|trim| trim.frame_pivot(Vec2::splat(0.5), false))
+                    .unwrap_or_default(),
+            );
+        }
+        let frame = Vec2::new(layout.len() as f32, 1.);
+        let layout = load_context.add_labeled_asset("layout".to_string(), layout);
+        let handles = Handles::new(image, layout.clone());
+
+        let mut tags = sheet.meta.frame_tags;
+        if tags.is_empty() && !sheet.frames.0.is_empty() {
+            let file_name = load_context
+                .path()
+                .path()
+                .file_name()
+                .and_then(|name| name.to_str())
+                .unwrap_or_default();
+            tags.push(AsepriteTag {
+                name: file_name.split('.').next().unwrap_or_default().to_string(),
+                from: 0,
+                to: sheet.frames.0.len() - 1,
+                direction: AsepriteDirection::Forward,
+                repeat: None,
+            });
+        }
+
+        let mut library = AnimationLibrary::default();
+        // Sheets exported with "Trim Cels" need pivots to keep the frames lined up
+        if pivots.iter().any(|pivot| *pivot != FramePivot::default()) {
+            library.frame_pivots.push((layout, pivots));
+        }
+        for tag in tags {
+            if tag.from > tag.to || tag.to >= sheet.frames.0.len() {
+                return Err(AnimationLibraryError::InvalidAnimation(format!(
+                    "Aseprite tag `{}` uses frames {}..={} but the sheet only has {} frames",
+                    tag.name,
+                    tag.from,
+                    tag.to,
+                    sheet.frames.0.len()
+                )));
+            }
+            let animation_frames = tag.animation_frames();
+            let frame_timings_in_secs = animation_frames
+                .iter()
+                .map(|index| sheet.frames.0[*index].1.duration as f32 / 1000.)
+                .collect();
+            let loop_mode = match tag.repeat.as_deref().map(str::parse::<usize>) {
+                Some(Ok(count)) if count > 0 => LoopMode::LoopCount(count),
+                _ => LoopMode::Loop,
+            };
+
+            library.animations.push(NewAnimation {
+                handles: handles.clone(),
+                animation: AnimationType::Timed(
+                    TimedAnimation::new(
+                        animation_frames,
+                        frame_timings_in_secs,
+                        frame,
+                        // The frames are atlas indexes already so we never want a row offset
+                        AnimationDirectionIndexes::FX(FXBasedDirection { index: 0 }),
+                        loop_mode,
+                        false,
+                        0,
+                    ),
+                    intern_animation_name(&tag.name),
+                ),
+            });
+        }
+        Ok(library)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["aseprite.json"]
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct AsepriteSheet {
+    frames: SheetFrames<AsepriteFrame>,
+    meta: AsepriteMeta,
+}
+
+#[derive(Debug, Deserialize)]
+struct AsepriteFrame {
+    frame: SheetRect,
+    #[serde(flatten)]
+    trim: Option<SheetTrim>,
+    /// The duration of the frame in milliseconds
+    duration: u32,
+}
+
+#[derive(Debug, Deserialize)]
+struct AsepriteMeta {
+    image: String,
+    size: SheetSize,
+    #[serde(default, rename = "frameTags")]
+    frame_tags: Vec<AsepriteTag>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AsepriteTag {
+    name: String,
+    from: usize,
+    to: usize,
+    #[serde(default)]
+    direction: AsepriteDirection,
+    #[serde(default)]
+    repeat: Option<String>,
+}
+
+impl AsepriteTag {
+    /// The atlas indexes of the tag in the order Aseprite plays them
+    fn animation_frames(&self) -> Vec<usize> {
+        let forward = self.from..=self.to;
+        // Ping-pong doesn't repeat the first and last frame when turning around
+        let backward = (self.from + 1..self.to).rev();
+        match self.direction {
+            AsepriteDirection::Forward => forward.collect(),
+            AsepriteDirection::Reverse => forward.rev().collect(),
+            AsepriteDirection::Pingpong => forward.chain(backward).collect(),
+            AsepriteDirection::PingpongReverse => {
+                forward.rev().chain(self.from + 1..self.to).collect()
+            }
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum AsepriteDirection {
+    #[default]
+    Forward,
+    Reverse,
+    Pingpong,
+    PingpongReverse,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SHEET: &str = r#"{
+        "frames": {
+            "hero 0.aseprite": { "frame": { "x": 0, "y": 0, "w": 16, "h": 16 }, "duration": 100 },
+            "hero 1.aseprite": {
+                "frame": { "x": 16, "y": 0, "w": 8, "h": 16 },
+                "spriteSourceSize": { "x": 4, "y": 0, "w": 8, "h": 16 },
+                "sourceSize": { "w": 16, "h": 16 },
+                "duration": 150
+            },
+            "hero 2.aseprite": { "frame": { "x": 24, "y": 0, "w": 16, "h": 16 }, "duration": 100 },
+            "hero 3.aseprite": { "frame": { "x": 40, "y": 0, "w": 16, "h": 16 }, "duration": 100 }
+        },
+        "meta": {
+            "image": "hero.png",
+            "size": { "w": 56, "h": 16 },
+            "frameTags": [
+                { "name": "walk", "from": 0, "to": 3, "direction": "forward" },
+                { "name": "wave", "from": 1, "to": 3, "direction": "reverse", "repeat": "2" },
+                { "name": "bob", "from": 0, "to": 3, "direction": "pingpong" },
+                { "name": "sway", "from": 0, "to": 3, "direction": "pingpong_reverse" }
+            ]
+        }
+    }"#;
+
+    #[test]
+    fn parses_the_hash_format_in_order() {
+        let sheet: AsepriteSheet = serde_json::from_str(SHEET).unwrap();
+        let names: Vec<_> = sheet
+            .frames
+            .0
+            .iter()
+            .map(|(name, _)| name.as_deref().unwrap())
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                "hero 0.aseprite",
+                "hero 1.aseprite",
+                "hero 2.aseprite",
+                "hero 3.aseprite"
+            ]
+        );
+        let (_, trimmed) = &sheet.frames.0[1];
+        assert_eq!(trimmed.duration, 150);
+        assert_eq!(trimmed.trim.unwrap().sprite_source_size.x, 4);
+        assert!(sheet.frames.0[0].1.trim.is_none());
+        assert_eq!(sheet.meta.image, "hero.png");
+        assert_eq!(sheet.meta.frame_tags[1].repeat.as_deref(), Some("2"));
+    }
+
+    #[test]
+    fn tags_play_their_frames_in_aseprite_order() {
+        let sheet: AsepriteSheet = serde_json::from_str(SHEET).unwrap();
+        let frames: Vec<_> = sheet
+            .meta
+            .frame_tags
+            .iter()
+            .map(AsepriteTag::animation_frames)
+            .collect();
+        assert_eq!(frames[0], vec![0, 1, 2, 3]);
+        assert_eq!(frames[1], vec![3, 2, 1]);
+        assert_eq!(frames[2], vec![0, 1, 2, 3, 2, 1]);
+        assert_eq!(frames[3], vec![3, 2, 1, 0, 1, 2]);
+    }
+}
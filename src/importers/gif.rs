@@ -0,0 +1,336 @@
+use bevy::asset::{io::Reader, AssetLoader, LoadContext, RenderAssetUsages};
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+
+use crate::*;
+
+/// Loads an animated GIF as an [AnimationLibrary]
+///
+/// The frames are composited the way a browser would show them, packed into a single [Image] with a grid
+/// [TextureAtlasLayout] and played by a [LinearTimedAnimation] named after the file with timings from the GIF's frame delays.
+///
+/// The animation is inserted into both the animation pool and the FX animation pool so it can be started with either an
+/// [AnimationEvent] or an [FXAnimationEvent]. GIFs that loop forever repeat, GIFs with a loop count play
+/// that many extra times using [LoopMode::LoopCount] and every other GIF plays once
+///
+/// **Note** the name is only the file name up to the first `.`, so `a/explosion.gif` and `b/explosion.gif` are both named
+/// `explosion` and whichever loads last replaces the other in [Animations]
+#[derive(Debug, Default, TypePath)]
+pub struct GifLoader;
+
+impl AssetLoader for GifLoader {
+    type Asset = AnimationLibrary;
+    type Settings = ();
+    type Error = AnimationLibraryError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<AnimationLibrary, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+
+        let decoded = decode_gif(&bytes)?;
+        if decoded.frames.is_empty() {
+            return Err(AnimationLibraryError::InvalidAnimation(format!(
+                "GIF {} has no frames",
+                load_context.path()
+            )));
+        }
+        let (width, height) = (decoded.width, decoded.height);
+        let (sheet, columns, rows) = pack_frames(&decoded.frames, width, height);
+        let image = Image::new(
+            Extent3d {
+                width: (columns * width) as u32,
+                height: (rows * height) as u32,
+                depth_or_array_layers: 1,
+            },
+            TextureDimension::D2,
+            sheet,
+            TextureFormat::Rgba8UnormSrgb,
+            RenderAssetUsages::default(),
+        );
+        let layout = SheetGrid::new(
+            UVec2::new(width as u32, height as u32),
+            columns as u32,
+            rows as u32,
+        )
+        .layout();
+        let handles = Handles::new(
+            load_context.add_labeled_asset("image".to_string(), image),
+            load_context.add_labeled_asset("layout".to_string(), layout),
+        );
+
+        let file_name = load_context
+            .path()
+            .path()
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or_default();
+        let name = intern_animation_name(file_name.split('.').next().unwrap_or_default());
+        let animation = NewAnimation {
+            handles,
+            animation: AnimationType::LinearTimed(
+                LinearTimedAnimation::new(
+                    (0..decoded.frames.len()).collect(),
+                    decoded.frame_timings_in_secs,
+                    decoded.loop_mode,
+                ),
+                name,
+            ),
+        };
+        Ok(AnimationLibrary {
+            animations: vec![animation.clone()],
+            fx_animations: vec![animation],
+            ..Default::default()
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["gif"]
+    }
+}
+
+/// The composited frames of a GIF and how they are played
+struct DecodedGif {
+    width: usize,
+    height: usize,
+    /// Every frame as RGBA pixels the size of the whole GIF
+    frames: Vec<Vec<u8>>,
+    frame_timings_in_secs: Vec<f32>,
+    loop_mode: LoopMode,
+}
+
+fn decode_gif(bytes: &[u8]) -> Result<DecodedGif, AnimationLibraryError> {
+    let mut options = gif::DecodeOptions::new();
+    options.set_color_output(gif::ColorOutput::RGBA);
+    let mut decoder = options.read_info(bytes)?;
+    let width = decoder.width() as usize;
+    let height = decoder.height() as usize;
+
+    let mut canvas = vec![0; width * height * 4];
+    let mut frames = Vec::new();
+    let mut frame_timings_in_secs = Vec::new();
+    while let Some(frame) = decoder.read_next_frame()? {
+        let previous = (frame.dispose == gif::DisposalMethod::Previous).then(|| canvas.clone());
+        draw_frame(&mut canvas, width, height, frame);
+        frames.push(canvas.clone());
+        frame_timings_in_secs.push(frame_delay_in_secs(frame.delay));
+
+        match frame.dispose {
+            gif::DisposalMethod::Background => {
+                clear_frame(&mut canvas, width, height, frame);
+            }
+            gif::DisposalMethod::Previous => {
+                if let Some(previous) = previous {
+                    canvas = previous;
+                }
+            }
+            gif::DisposalMethod::Any | gif::DisposalMethod::Keep => {}
+        }
+    }
+    Ok(DecodedGif {
+        width,
+        height,
+        frames,
+        frame_timings_in_secs,
+        loop_mode: loop_mode(decoder.repeat()),
+    })
+}
+
+/// The NETSCAPE loop count is how many times the GIF repeats after playing once, like browsers show it
+fn loop_mode(repeat: gif::Repeat) -> LoopMode {
+    match repeat {
+        gif::Repeat::Infinite => LoopMode::Loop,
+        gif::Repeat::Finite(0) => LoopMode::Once,
+        gif::Repeat::Finite(repeats) => LoopMode::LoopCount(repeats as usize + 1),
+    }
+}
+
+/// Packs the frames into a square-ish grid so large GIFs don't exceed texture size limits in one direction
+///
+/// Returns the pixels of the sheet and how many columns and rows of frames it has
+fn pack_frames(frames: &[Vec<u8>], width: usize, height: usize) -> (Vec<u8>, usize, usize) {
+    let columns = (frames.len() as f32).sqrt().ceil() as usize;
+    let rows = frames.len().div_ceil(columns);
+    let sheet_width = columns * width;
+    let mut sheet = vec![0; sheet_width * rows * height * 4];
+    for (index, frame) in frames.iter().enumerate() {
+        let (column, row) = (index % columns, index / columns);
+        for y in 0..height {
+            let source = y * width * 4;
+            let target = ((row * height + y) * sheet_width + column * width) * 4;
+            sheet[target..target + width * 4].copy_from_slice(&frame[source..source + width * 4]);
+        }
+    }
+    (sheet, columns, rows)
+}
+
+/// GIF delays are in hundredths of a second. Like browsers, delays that are too short to be intended are shown for 0.1 seconds
+fn frame_delay_in_secs(delay: u16) -> f32 {
+    if delay <= 1 {
+        0.1
+    } else {
+        delay as f32 / 100.
+    }
+}
+
+/// Draws the opaque pixels of a frame onto the canvas
+fn draw_frame(canvas: &mut [u8], width: usize, height: usize, frame: &gif::Frame) {
+    for y in 0..frame.height as usize {
+        let canvas_y = frame.top as usize + y;
+        if canvas_y >= height {
+            break;
+        }
+        for x in 0..frame.width as usize {
+            let canvas_x = frame.left as usize + x;
+            if canvas_x >= width {
+                break;
+            }
+            let source = (y * frame.width as usize + x) * 4;
+            let Some(pixel) = frame.buffer.get(source..source + 4) else {
+                return;
+            };
+            // Transparent pixels let the previous frame show through
+            if pixel[3] == 0 {
+                continue;
+            }
+            let target = (canvas_y * width + canvas_x) * 4;
+            canvas[target..target + 4].copy_from_slice(pixel);
+        }
+    }
+}
+
+/// Clears the area of a frame to transparent for the "restore to background" disposal
+fn clear_frame(canvas: &mut [u8], width: usize, height: usize, frame: &gif::Frame) {
+    let right = (frame.left as usize + frame.width as usize).min(width);
+    let bottom = (frame.top as usize + frame.height as usize).min(height);
+    for y in frame.top as usize..bottom {
+        for x in frame.left as usize..right {
+            let target = (y * width + x) * 4;
+            canvas[target..target + 4].fill(0);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use super::*;
+
+    const CLEAR: [u8; 4] = [0, 0, 0, 0];
+    const RED: [u8; 4] = [255, 0, 0, 255];
+    const GREEN: [u8; 4] = [0, 255, 0, 255];
+
+    /// A frame using the palette of [encode], where index 0 is transparent, 1 is red and 2 is green
+    fn frame(
+        left: u16,
+        width: u16,
+        pixels: &[u8],
+        dispose: gif::DisposalMethod,
+    ) -> gif::Frame<'static> {
+        gif::Frame {
+            left,
+            width,
+            height: 1,
+            dispose,
+            transparent: Some(0),
+            buffer: Cow::Owned(pixels.to_vec()),
+            ..Default::default()
+        }
+    }
+
+    /// A GIF one pixel tall with the frames specified
+    fn encode(width: u16, repeat: Option<gif::Repeat>, frames: &[gif::Frame]) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        {
+            let palette = [0, 0, 0, 255, 0, 0, 0, 255, 0];
+            let mut encoder = gif::Encoder::new(&mut bytes, width, 1, &palette).unwrap();
+            if let Some(repeat) = repeat {
+                encoder.set_repeat(repeat).unwrap();
+            }
+            for frame in frames {
+                encoder.write_frame(frame).unwrap();
+            }
+        }
+        bytes
+    }
+
+    fn pixels(frame: &[u8]) -> Vec<[u8; 4]> {
+        frame
+            .chunks(4)
+            .map(|pixel| pixel.try_into().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn background_disposal_clears_the_frame() {
+        let bytes = encode(
+            2,
+            None,
+            &[
+                frame(0, 2, &[1, 1], gif::DisposalMethod::Background),
+                frame(1, 1, &[2], gif::DisposalMethod::Keep),
+            ],
+        );
+        let decoded = decode_gif(&bytes).unwrap();
+        assert_eq!(pixels(&decoded.frames[0]), [RED, RED]);
+        assert_eq!(pixels(&decoded.frames[1]), [CLEAR, GREEN]);
+    }
+
+    #[test]
+    fn previous_disposal_restores_the_canvas() {
+        let bytes = encode(
+            2,
+            None,
+            &[
+                frame(0, 2, &[1, 1], gif::DisposalMethod::Keep),
+                frame(0, 1, &[2], gif::DisposalMethod::Previous),
+                // A transparent pixel leaves the canvas as it is
+                frame(1, 1, &[0], gif::DisposalMethod::Keep),
+            ],
+        );
+        let decoded = decode_gif(&bytes).unwrap();
+        assert_eq!(pixels(&decoded.frames[0]), [RED, RED]);
+        assert_eq!(pixels(&decoded.frames[1]), [GREEN, RED]);
+        assert_eq!(pixels(&decoded.frames[2]), [RED, RED]);
+    }
+
+    #[test]
+    fn repeat_count_maps_to_the_loop_mode() {
+        let frames = [frame(0, 1, &[1], gif::DisposalMethod::Keep)];
+        let loop_mode = |repeat| decode_gif(&encode(1, repeat, &frames)).unwrap().loop_mode;
+        assert_eq!(loop_mode(None), LoopMode::Once);
+        assert_eq!(loop_mode(Some(gif::Repeat::Infinite)), LoopMode::Loop);
+        assert_eq!(
+            loop_mode(Some(gif::Repeat::Finite(2))),
+            LoopMode::LoopCount(3)
+        );
+    }
+
+    #[test]
+    fn frame_delays_are_hundredths_of_a_second() {
+        assert_eq!(frame_delay_in_secs(0), 0.1);
+        assert_eq!(frame_delay_in_secs(1), 0.1);
+        assert_eq!(frame_delay_in_secs(2), 0.02);
+        assert_eq!(frame_delay_in_secs(150), 1.5);
+
+        let mut slow = frame(0, 1, &[1], gif::DisposalMethod::Keep);
+        slow.delay = 25;
+        let decoded = decode_gif(&encode(1, None, &[slow])).unwrap();
+        assert_eq!(decoded.frame_timings_in_secs, [0.25]);
+    }
+
+    #[test]
+    fn frames_are_packed_row_by_row() {
+        // 5 frames two pixels wide, each filled with its index
+        let frames: Vec<Vec<u8>> = (0..5).map(|index| vec![index; 8]).collect();
+        let (sheet, columns, rows) = pack_frames(&frames, 2, 1);
+        assert_eq!((columns, rows), (3, 2));
+        let sheet: Vec<u8> = pixels(&sheet).iter().map(|pixel| pixel[0]).collect();
+        // The last cell of the grid is left empty
+        assert_eq!(sheet, [0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 0, 0]);
+    }
+}
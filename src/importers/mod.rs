@@ -0,0 +1,146 @@
+//! Loaders that turn the output of other art tools into an [AnimationLibrary](crate::AnimationLibrary)
+
+use std::fmt;
+
+use serde::de::{Deserializer, MapAccess, SeqAccess, Visitor};
+
+use crate::*;
+
+mod aseprite;
+mod gif;
+mod texture_packer;
+mod tiled;
+
+pub use aseprite::*;
+pub use gif::*;
+pub use texture_packer::*;
+pub use tiled::*;
+
+/// A rect inside a packed sprite sheet as written by Aseprite and TexturePacker
+#[derive(Debug, Clone, Copy, Deserialize)]
+struct SheetRect {
+    x: u32,
+    y: u32,
+    w: u32,
+    h: u32,
+}
+
+impl SheetRect {
+    fn urect(&self) -> URect {
+        URect::new(self.x, self.y, self.x + self.w, self.y + self.h)
+    }
+}
+
+/// The trimming information of a frame in a packed sprite sheet as written by Aseprite and TexturePacker
+#[derive(Debug, Clone, Copy, Deserialize)]
+struct SheetTrim {
+    /// Where the trimmed frame sits inside the untrimmed frame
+    #[serde(rename = "spriteSourceSize")]
+    sprite_source_size: SheetRect,
+    /// The size of the untrimmed frame
+    #[serde(rename = "sourceSize")]
+    source_size: SheetSize,
+}
+
+impl SheetTrim {
+    /// Works out the [FramePivot] that puts `pivot` of the untrimmed frame at the sprite's origin
+    ///
+    /// `pivot` is normalized with the origin at the top left like Aseprite and TexturePacker write it
+    fn frame_pivot(&self, pivot: Vec2, rotated: bool) -> FramePivot {
+        let trimmed = self.sprite_source_size;
+        let pivot_x = pivot.x * self.source_size.w as f32 - trimmed.x as f32;
+        let pivot_y = pivot.y * self.source_size.h as f32 - trimmed.y as f32;
+        let u = pivot_x / trimmed.w.max(1) as f32;
+        let v = pivot_y / trimmed.h.max(1) as f32;
+        let anchor = if rotated {
+            // The sheet stores the frame rotated clockwise so (u, v) ends up at (1 - v, u)
+            Vec2::new(0.5 - v, 0.5 - u)
+        } else {
+            Vec2::new(u - 0.5, 0.5 - v)
+        };
+        FramePivot { anchor, rotated }
+    }
+}
+
+/// A size inside a packed sprite sheet as written by Aseprite and TexturePacker
+#[derive(Debug, Clone, Copy, Deserialize)]
+struct SheetSize {
+    w: u32,
+    h: u32,
+}
+
+/// The `frames` of a packed sprite sheet which are either a "JSON Array" or a "JSON Hash" keyed by frame name
+///
+/// Hash entries are kept in the order they are written in the file since that is the frame order
+#[derive(Debug)]
+struct SheetFrames<F>(Vec<(Option<String>, F)>);
+
+impl<'de, F: Deserialize<'de>> Deserialize<'de> for SheetFrames<F> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct FramesVisitor<F>(std::marker::PhantomData<F>);
+
+        impl<'de, F: Deserialize<'de>> Visitor<'de> for FramesVisitor<F> {
+            type Value = SheetFrames<F>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("an array or map of frames")
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let mut frames = Vec::new();
+                while let Some(frame) = seq.next_element()? {
+                    frames.push((None, frame));
+                }
+                Ok(SheetFrames(frames))
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                let mut frames = Vec::new();
+                while let Some((name, frame)) = map.next_entry::<String, F>()? {
+                    frames.push((Some(name), frame));
+                }
+                Ok(SheetFrames(frames))
+            }
+        }
+
+        deserializer.deserialize_any(FramesVisitor(std::marker::PhantomData))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn trim(x: u32, y: u32, w: u32, h: u32, source: u32) -> SheetTrim {
+        SheetTrim {
+            sprite_source_size: SheetRect { x, y, w, h },
+            source_size: SheetSize {
+                w: source,
+                h: source,
+            },
+        }
+    }
+
+    #[test]
+    fn untrimmed_frames_keep_the_centered_anchor() {
+        let pivot = trim(0, 0, 32, 32, 32).frame_pivot(Vec2::splat(0.5), false);
+        assert_eq!(pivot, FramePivot::default());
+    }
+
+    #[test]
+    fn trimmed_frames_move_the_pivot_to_the_untrimmed_center() {
+        // Only the top left quarter of the frame is left after trimming
+        let quarter = trim(0, 0, 16, 16, 32);
+        assert_eq!(
+            quarter.frame_pivot(Vec2::splat(0.5), false).anchor,
+            Vec2::new(0.5, -0.5)
+        );
+        assert_eq!(
+            quarter.frame_pivot(Vec2::ZERO, false).anchor,
+            Vec2::new(-0.5, 0.5)
+        );
+        let rotated = quarter.frame_pivot(Vec2::splat(0.5), true);
+        assert_eq!(rotated.anchor, Vec2::new(-0.5, -0.5));
+        assert!(rotated.rotated);
+    }
+}
@@ -0,0 +1,282 @@
+use std::collections::BTreeMap;
+
+use bevy::asset::{io::Reader, AssetLoader, LoadContext};
+
+use super::{SheetFrames, SheetRect, SheetSize, SheetTrim};
+use crate::*;
+
+/// Loads a TexturePacker or Free Texture Packer "JSON (Hash)" or "JSON (Array)" sheet (`.atlas.json`) as an [AnimationLibrary]
+///
+/// Frames are grouped into [TimedAnimation]s by their name without the frame number and extension,
+/// so `run_01.png` through `run_12.png` become the `run` animation.
+/// If the sheet has an `animations` section like the Phaser and PixiJS exports it is used instead.
+///
+/// Trimmed and rotated frames get a [FramePivot] so the sprite doesn't jitter when the frames change
+#[derive(Debug, Default, TypePath)]
+pub struct TexturePackerLoader;
+
+/// Settings for the [TexturePackerLoader] since packed sheets have no timing information
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TexturePackerSettings {
+    /// The duration of every frame
+    pub frame_duration_in_secs: f32,
+    /// If the animations repeat
+    pub repeating: bool,
+}
+
+impl Default for TexturePackerSettings {
+    fn default() -> Self {
+        Self {
+            frame_duration_in_secs: 0.1,
+            repeating: true,
+        }
+    }
+}
+
+impl AssetLoader for TexturePackerLoader {
+    type Asset = AnimationLibrary;
+    type Settings = TexturePackerSettings;
+    type Error = AnimationLibraryError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        settings: &TexturePackerSettings,
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<AnimationLibrary, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let sheet: TexturePackerSheet = serde_json::from_slice(&bytes)?;
+        let frames = sheet.frames.0;
+
+        let image_path = load_context.path().resolve_embed(&sheet.meta.image)?;
+        let image = load_context.load::<Image>(image_path);
+        let mut layout =
+            TextureAtlasLayout::new_empty(UVec2::new(sheet.meta.size.w, sheet.meta.size.h));
+        let mut names = Vec::with_capacity(frames.len());
+        let mut pivots = Vec::with_capacity(frames.len());
+        let mut needs_pivots = false;
+        for (key, frame) in frames.iter() {
+            // Rotated frames keep their unrotated size in the file
+            let rect = frame.frame;
+            let rect = if frame.rotated {
+                URect::new(rect.x, rect.y, rect.x + rect.h, rect.y + rect.w)
+            } else {
+                rect.urect()
+            };
+            layout.add_texture(rect);
+            names.push(key.clone().or(frame.filename.clone()).unwrap_or_default());
+
+            let trim = frame.trim.unwrap_or(SheetTrim {
+                sprite_source_size: SheetRect {
+                    x: 0,
+                    y: 0,
+                    ..frame.frame
+                },
+                source_size: SheetSize {
+                    w: frame.frame.w,
+                    h: frame.frame.h,
+                },
+            });
+            let pivot = frame
+                .pivot
+                .map(|pivot| Vec2::new(pivot.x, pivot.y))
+                .unwrap_or(Vec2::splat(0.5));
+            let frame_pivot = trim.frame_pivot(pivot, frame.rotated);
+            needs_pivots |= frame_pivot != FramePivot::default();
+            pivots.push(frame_pivot);
+        }
+        let frame = Vec2::new(layout.len() as f32, 1.);
+        let layout = load_context.add_labeled_asset("layout".to_string(), layout);
+        let handles = Handles::new(image, layout.clone());
+
+        let animations = match sheet.animations {
+            Some(animations) => {
+                let mut named_animations = Vec::new();
+                for (name, frame_names) in animations {
+                    let mut animation_frames = Vec::with_capacity(frame_names.len());
+                    for frame_name in frame_names.iter() {
+                        let Some(index) = names.iter().position(|name| name == frame_name) else {
+                            return Err(AnimationLibraryError::InvalidAnimation(format!(
+                                "Animation `{name}` uses frame `{frame_name}` which isn't in the sheet"
+                            )));
+                        };
+                        animation_frames.push(index);
+                    }
+                    named_animations.push((name, animation_frames));
+                }
+                named_animations
+            }
+            None => group_frames(&names),
+        };
+
+        let mut library = AnimationLibrary::default();
+        for (name, animation_frames) in animations {
+            if animation_frames.is_empty() {
+                continue;
+            }
+            let frame_timings_in_secs =
+                vec![settings.frame_duration_in_secs; animation_frames.len()];
+            library.animations.push(NewAnimation {
+                handles: handles.clone(),
+                animation: AnimationType::Timed(
+                    TimedAnimation::new(
+                        animation_frames,
+                        frame_timings_in_secs,
+                        frame,
+                        // The frames are atlas indexes already so we never want a row offset
+                        AnimationDirectionIndexes::FX(FXBasedDirection { index: 0 }),
+                        settings.repeating,
+                        false,
+                        0,
+                    ),
+                    intern_animation_name(&name),
+                ),
+            });
+        }
+        if needs_pivots {
+            library.frame_pivots.push((layout, pivots));
+        }
+        Ok(library)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["atlas.json"]
+    }
+}
+
+/// Groups frame names like `run_01.png` into animations like `run` ordered by their frame number
+fn group_frames(names: &[String]) -> Vec<(String, Vec<usize>)> {
+    let mut animations: Vec<(String, Vec<(u32, usize)>)> = Vec::new();
+    for (index, name) in names.iter().enumerate() {
+        let stem = name
+            .rsplit_once('.')
+            .map_or(name.as_str(), |(stem, _)| stem);
+        let without_number = stem.trim_end_matches(|c: char| c.is_ascii_digit());
+        let number = stem[without_number.len()..].parse().unwrap_or_default();
+        let animation_name = without_number.trim_end_matches(['_', '-', ' ', '.']);
+
+        match animations
+            .iter_mut()
+            .find(|(name, _)| name == animation_name)
+        {
+            Some((_, frames)) => frames.push((number, index)),
+            None => animations.push((animation_name.to_string(), vec![(number, index)])),
+        }
+    }
+    animations
+        .into_iter()
+        .map(|(name, mut frames)| {
+            frames.sort_by_key(|(number, _)| *number);
+            (name, frames.into_iter().map(|(_, index)| index).collect())
+        })
+        .collect()
+}
+
+#[derive(Debug, Deserialize)]
+struct TexturePackerSheet {
+    frames: SheetFrames<TexturePackerFrame>,
+    meta: TexturePackerMeta,
+    /// Sorted by name so the animations are always added in the same order
+    #[serde(default)]
+    animations: Option<BTreeMap<String, Vec<String>>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TexturePackerFrame {
+    /// Only written by the "JSON (Array)" format
+    #[serde(default)]
+    filename: Option<String>,
+    frame: SheetRect,
+    #[serde(default)]
+    rotated: bool,
+    #[serde(flatten)]
+    trim: Option<SheetTrim>,
+    #[serde(default)]
+    pivot: Option<TexturePackerPivot>,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+struct TexturePackerPivot {
+    x: f32,
+    y: f32,
+}
+
+#[derive(Debug, Deserialize)]
+struct TexturePackerMeta {
+    image: String,
+    size: SheetSize,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn groups_frames_by_name_in_frame_number_order() {
+        let names: Vec<String> = [
+            "run_02.png",
+            "idle.png",
+            "run_01.png",
+            "run_10.png",
+            "jump-1",
+        ]
+        .into_iter()
+        .map(String::from)
+        .collect();
+        assert_eq!(
+            group_frames(&names),
+            vec![
+                ("run".to_string(), vec![2, 0, 3]),
+                ("idle".to_string(), vec![1]),
+                ("jump".to_string(), vec![4]),
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_the_array_format() {
+        let sheet: TexturePackerSheet = serde_json::from_str(
+            r#"{
+                "frames": [
+                    {
+                        "filename": "run_01.png",
+                        "frame": { "x": 0, "y": 0, "w": 10, "h": 20 },
+                        "rotated": true,
+                        "spriteSourceSize": { "x": 3, "y": 0, "w": 20, "h": 10 },
+                        "sourceSize": { "w": 32, "h": 32 },
+                        "pivot": { "x": 0.5, "y": 1 }
+                    },
+                    { "filename": "run_02.png", "frame": { "x": 20, "y": 0, "w": 16, "h": 16 } }
+                ],
+                "meta": { "image": "run.png", "size": { "w": 36, "h": 20 } },
+                "animations": { "run": ["run_01.png", "run_02.png"] }
+            }"#,
+        )
+        .unwrap();
+        let (key, rotated) = &sheet.frames.0[0];
+        assert_eq!(*key, None);
+        assert_eq!(rotated.filename.as_deref(), Some("run_01.png"));
+        assert!(rotated.rotated);
+        assert_eq!(rotated.trim.unwrap().source_size.w, 32);
+        assert_eq!(rotated.pivot.unwrap().y, 1.);
+        let (_, plain) = &sheet.frames.0[1];
+        assert!(!plain.rotated);
+        assert!(plain.trim.is_none() && plain.pivot.is_none());
+        assert_eq!(sheet.animations.unwrap()["run"].len(), 2);
+    }
+
+    #[test]
+    fn animations_section_is_read_in_name_order() {
+        let sheet: TexturePackerSheet = serde_json::from_str(
+            r#"{
+                "frames": [{ "filename": "a.png", "frame": { "x": 0, "y": 0, "w": 8, "h": 8 } }],
+                "meta": { "image": "sheet.png", "size": { "w": 8, "h": 8 } },
+                "animations": { "walk": ["a.png"], "idle": ["a.png"], "run": ["a.png"] }
+            }"#,
+        )
+        .unwrap();
+        let names: Vec<_> = sheet.animations.unwrap().into_keys().collect();
+        assert_eq!(names, ["idle", "run", "walk"]);
+    }
+}
@@ -0,0 +1,246 @@
+use bevy::asset::{io::Reader, AssetLoader, LoadContext};
+
+use crate::*;
+
+/// Loads the animated tiles of a Tiled tileset (`.tsx` or `.tsj`) as an [AnimationLibrary]
+///
+/// Every tile with an animation becomes a repeating [LinearTimedAnimation] named `"{tileset name}:{tile id}"`.
+/// Use [AnimationLibrary::tile_animation] to get the name of the animation for a tile id
+///
+/// **Note** only tilesets based on a single image are supported
+#[derive(Debug, Default, TypePath)]
+pub struct TiledTilesetLoader;
+
+impl AssetLoader for TiledTilesetLoader {
+    type Asset = AnimationLibrary;
+    type Settings = ();
+    type Error = AnimationLibraryError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<AnimationLibrary, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let is_json = load_context
+            .path()
+            .get_full_extension()
+            .is_some_and(|extension| extension.ends_with("tsj"));
+        let tileset = if is_json {
+            serde_json::from_slice(&bytes)?
+        } else {
+            Tileset::from_tsx(&String::from_utf8_lossy(&bytes))?
+        };
+
+        let Some(image) = tileset.image.as_ref() else {
+            return Err(AnimationLibraryError::InvalidAnimation(format!(
+                "Tileset `{}` is an image collection which isn't supported",
+                tileset.name
+            )));
+        };
+        let image_path = load_context.path().resolve_embed(image)?;
+        let image = load_context.load::<Image>(image_path);
+        let columns = tileset.columns.max(1);
+        let layout = SheetGrid::new(
+            UVec2::new(tileset.tile_width, tileset.tile_height),
+            columns,
+            tileset.tile_count.div_ceil(columns),
+        )
+        .with_padding(UVec2::splat(tileset.spacing))
+        .with_offset(UVec2::splat(tileset.margin))
+        .layout();
+        let layout = load_context.add_labeled_asset("layout".to_string(), layout);
+        let handles = Handles::new(image, layout);
+
+        let mut library = AnimationLibrary::default();
+        for tile in tileset.tiles {
+            if tile.animation.is_empty() {
+                continue;
+            }
+            let animation_frames = tile
+                .animation
+                .iter()
+                .map(|frame| frame.tile_id as usize)
+                .collect();
+            let frame_timings_in_secs = tile
+                .animation
+                .iter()
+                .map(|frame| frame.duration as f32 / 1000.)
+                .collect();
+            let name = intern_animation_name(&format!("{}:{}", tileset.name, tile.id));
+            library.tile_animations.insert(tile.id, name);
+            library.animations.push(NewAnimation {
+                handles: handles.clone(),
+                animation: AnimationType::LinearTimed(
+                    LinearTimedAnimation::new(animation_frames, frame_timings_in_secs, true),
+                    name,
+                ),
+            });
+        }
+        Ok(library)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["tsx", "tsj"]
+    }
+}
+
+/// The parts of a Tiled tileset needed for animated tiles. Deserialized from `.tsj` and parsed by hand from `.tsx`
+#[derive(Debug, Default, Deserialize)]
+struct Tileset {
+    name: String,
+    #[serde(rename = "tilewidth")]
+    tile_width: u32,
+    #[serde(rename = "tileheight")]
+    tile_height: u32,
+    #[serde(default)]
+    spacing: u32,
+    #[serde(default)]
+    margin: u32,
+    #[serde(rename = "tilecount")]
+    tile_count: u32,
+    columns: u32,
+    #[serde(default)]
+    image: Option<String>,
+    #[serde(default)]
+    tiles: Vec<Tile>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct Tile {
+    id: u32,
+    #[serde(default)]
+    animation: Vec<TileFrame>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct TileFrame {
+    #[serde(rename = "tileid")]
+    tile_id: u32,
+    /// The duration of the frame in milliseconds
+    duration: u32,
+}
+
+impl Tileset {
+    fn from_tsx(text: &str) -> Result<Self, AnimationLibraryError> {
+        let document = roxmltree::Document::parse(text)?;
+        let root = document.root_element();
+        if !root.has_tag_name("tileset") {
+            return Err(AnimationLibraryError::InvalidAnimation(format!(
+                "Expected a `tileset` but found `{}`",
+                root.tag_name().name()
+            )));
+        }
+
+        let mut tileset = Tileset {
+            name: root.attribute("name").unwrap_or_default().to_string(),
+            tile_width: tsx_number(root, "tilewidth")?,
+            tile_height: tsx_number(root, "tileheight")?,
+            spacing: tsx_number(root, "spacing").unwrap_or_default(),
+            margin: tsx_number(root, "margin").unwrap_or_default(),
+            tile_count: tsx_number(root, "tilecount")?,
+            columns: tsx_number(root, "columns")?,
+            ..Default::default()
+        };
+        for node in root.children().filter(|node| node.is_element()) {
+            if node.has_tag_name("image") {
+                tileset.image = node.attribute("source").map(str::to_string);
+            } else if node.has_tag_name("tile") {
+                let mut tile = Tile {
+                    id: tsx_number(node, "id")?,
+                    ..Default::default()
+                };
+                let frames = node
+                    .children()
+                    .filter(|child| child.has_tag_name("animation"))
+                    .flat_map(|animation| animation.children())
+                    .filter(|frame| frame.has_tag_name("frame"));
+                for frame in frames {
+                    tile.animation.push(TileFrame {
+                        tile_id: tsx_number(frame, "tileid")?,
+                        duration: tsx_number(frame, "duration")?,
+                    });
+                }
+                tileset.tiles.push(tile);
+            }
+        }
+        Ok(tileset)
+    }
+}
+
+/// Reads a numeric attribute from a `.tsx` element
+fn tsx_number(node: roxmltree::Node, attribute: &str) -> Result<u32, AnimationLibraryError> {
+    node.attribute(attribute)
+        .and_then(|value| value.parse().ok())
+        .ok_or_else(|| {
+            AnimationLibraryError::InvalidAnimation(format!(
+                "`{}` is missing a valid `{attribute}`",
+                node.tag_name().name()
+            ))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_water(tileset: &Tileset) {
+        assert_eq!(tileset.name, "water");
+        assert_eq!((tileset.tile_width, tileset.tile_height), (16, 16));
+        assert_eq!((tileset.spacing, tileset.margin), (1, 2));
+        assert_eq!((tileset.tile_count, tileset.columns), (8, 4));
+        assert_eq!(tileset.image.as_deref(), Some("water.png"));
+        assert_eq!(tileset.tiles.len(), 2);
+        assert!(tileset.tiles[0].animation.is_empty());
+        let frames: Vec<_> = tileset.tiles[1]
+            .animation
+            .iter()
+            .map(|frame| (frame.tile_id, frame.duration))
+            .collect();
+        assert_eq!(frames, vec![(5, 100), (6, 250)]);
+    }
+
+    #[test]
+    fn parses_tsx() {
+        let tileset = Tileset::from_tsx(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+            <tileset version="1.10" name="water" tilewidth="16" tileheight="16" spacing="1" margin="2" tilecount="8" columns="4">
+                <image source="water.png" width="70" height="38"/>
+                <tile id="0" type="wall"/>
+                <tile id="5">
+                    <animation>
+                        <frame tileid="5" duration="100"/>
+                        <frame tileid="6" duration="250"/>
+                    </animation>
+                </tile>
+            </tileset>"#,
+        )
+        .unwrap();
+        assert_water(&tileset);
+    }
+
+    #[test]
+    fn parses_tsj() {
+        let tileset: Tileset = serde_json::from_str(
+            r#"{
+                "name": "water", "tilewidth": 16, "tileheight": 16, "spacing": 1, "margin": 2,
+                "tilecount": 8, "columns": 4, "image": "water.png", "imagewidth": 70, "imageheight": 38,
+                "tiles": [
+                    { "id": 0, "type": "wall" },
+                    { "id": 5, "animation": [{ "tileid": 5, "duration": 100 }, { "tileid": 6, "duration": 250 }] }
+                ]
+            }"#,
+        )
+        .unwrap();
+        assert_water(&tileset);
+    }
+
+    #[test]
+    fn rejects_tsx_without_a_tileset() {
+        assert!(Tileset::from_tsx(r#"<map name="water"/>"#).is_err());
+        let missing_size = r#"<tileset name="water" tileheight="16" tilecount="8" columns="4"/>"#;
+        assert!(Tileset::from_tsx(missing_size).is_err());
+    }
+}
@@ -0,0 +1,181 @@
+use std::fmt::Debug;
+
+use crate::*;
+
+/// Maps a frame of an animation facing a direction to an index in its sprite sheet
+///
+/// `frame` is the value from `animation_frames` (or `x_index_pos`) and `direction` is the index picked by the
+/// animation's [AnimationDirectionIndexes]. Implement this for sheets that aren't laid out like any of the built-ins
+///
+/// # Example
+/// ```rust
+/// /// Every direction has its own sheet that is 2 frames wide
+/// #[derive(Debug)]
+/// struct TwoWide;
+///
+/// impl SheetIndexing for TwoWide {
+///     fn atlas_index(&self, frame: usize, direction: usize, grid: &SheetGrid) -> usize {
+///         grid.index(direction * 2 + frame % 2, frame / 2)
+///     }
+/// }
+///
+/// let animation = TimedAnimation::new(/* ... */).with_sheet_indexing(TwoWide);
+/// ```
+pub trait SheetIndexing: Debug + Send + Sync + 'static {
+    fn atlas_index(&self, frame: usize, direction: usize, grid: &SheetGrid) -> usize;
+}
+
+/// The frames of each direction are a row. This is the default
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RowMajor;
+
+impl SheetIndexing for RowMajor {
+    fn atlas_index(&self, frame: usize, direction: usize, grid: &SheetGrid) -> usize {
+        grid.index(frame, direction)
+    }
+}
+
+/// The frames of each direction are a column
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ColumnMajor;
+
+impl SheetIndexing for ColumnMajor {
+    fn atlas_index(&self, frame: usize, direction: usize, grid: &SheetGrid) -> usize {
+        grid.index(direction, frame)
+    }
+}
+
+/// The frames of each direction continue on the next row when they reach the end of one.
+/// Every direction starts on a new row and takes up `rows_per_direction` rows
+#[derive(Debug, Clone, Copy)]
+pub struct WrappedRows {
+    pub rows_per_direction: usize,
+}
+
+impl SheetIndexing for WrappedRows {
+    fn atlas_index(&self, frame: usize, direction: usize, grid: &SheetGrid) -> usize {
+        grid.index(0, direction * self.rows_per_direction) + frame
+    }
+}
+
+/// The values in `animation_frames` are atlas indexes and used as they are, no matter the direction
+///
+/// Use this for animations that take frames from different rows, like a spin that cycles through every direction.
+/// [SheetGrid::cell_indexes] turns `(column, row)` cells into atlas indexes
+///
+/// **Note** [FlipBasedDirection]s still flip the sprite
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AtlasIndexes;
+
+impl SheetIndexing for AtlasIndexes {
+    fn atlas_index(&self, frame: usize, _direction: usize, _grid: &SheetGrid) -> usize {
+        frame
+    }
+}
+
+/// Looks up the atlas index of every frame in a table where `table[direction][frame]` is the atlas index
+///
+/// Frames or directions missing from the table fall back to [RowMajor]
+#[derive(Debug, Clone, Default)]
+pub struct IndexTable(pub Vec<Vec<usize>>);
+
+impl SheetIndexing for IndexTable {
+    fn atlas_index(&self, frame: usize, direction: usize, grid: &SheetGrid) -> usize {
+        match self.0.get(direction).and_then(|frames| frames.get(frame)) {
+            Some(index) => *index,
+            None => RowMajor.atlas_index(frame, direction, grid),
+        }
+    }
+}
+
+/// The [SheetIndexing] used by an animation. Defaults to [RowMajor]
+#[derive(Debug, Clone, Deref)]
+pub struct SheetIndexer(Arc<dyn SheetIndexing>);
+
+impl SheetIndexer {
+    pub fn new(indexing: impl SheetIndexing) -> Self {
+        Self(Arc::new(indexing))
+    }
+}
+
+impl Default for SheetIndexer {
+    fn default() -> Self {
+        Self::new(RowMajor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A sheet 4 frames wide and 6 rows tall
+    fn grid() -> SheetGrid {
+        SheetGrid::new(UVec2::splat(16), 4, 6)
+    }
+
+    /// The atlas indexes of `frames` facing `direction`
+    fn indexes(indexing: impl SheetIndexing, frames: &[usize], direction: usize) -> Vec<usize> {
+        let grid = grid();
+        frames
+            .iter()
+            .map(|frame| indexing.atlas_index(*frame, direction, &grid))
+            .collect()
+    }
+
+    #[test]
+    fn row_major_directions_are_rows() {
+        assert_eq!(indexes(RowMajor, &[0, 1, 2], 0), [0, 1, 2]);
+        assert_eq!(indexes(RowMajor, &[0, 1, 2], 2), [8, 9, 10]);
+    }
+
+    #[test]
+    fn column_major_directions_are_columns() {
+        assert_eq!(indexes(ColumnMajor, &[0, 1, 2], 0), [0, 4, 8]);
+        assert_eq!(indexes(ColumnMajor, &[0, 1, 2], 3), [3, 7, 11]);
+    }
+
+    #[test]
+    fn wrapped_rows_continue_on_the_next_row() {
+        let wrapped = || WrappedRows {
+            rows_per_direction: 2,
+        };
+        assert_eq!(indexes(wrapped(), &[0, 3, 4, 7], 0), [0, 3, 4, 7]);
+        // The second direction starts on the third row
+        assert_eq!(indexes(wrapped(), &[0, 3, 4, 7], 1), [8, 11, 12, 15]);
+        assert_eq!(indexes(wrapped(), &[5], 2), [21]);
+    }
+
+    #[test]
+    fn index_tables_fall_back_to_row_major() {
+        let table = || IndexTable(vec![vec![7, 3], vec![20]]);
+        assert_eq!(indexes(table(), &[0, 1], 0), [7, 3]);
+        // Frames missing from a direction
+        assert_eq!(indexes(table(), &[0, 1, 2], 1), [20, 5, 6]);
+        // Directions missing from the table
+        assert_eq!(indexes(table(), &[0, 1], 3), [12, 13]);
+    }
+
+    #[test]
+    fn cells_are_the_same_in_every_direction() {
+        let frames = grid().cell_indexes(&[(0, 0), (3, 1), (2, 5)]);
+        assert_eq!(frames, [0, 7, 22]);
+        assert_eq!(indexes(AtlasIndexes, &frames, 0), [0, 7, 22]);
+        assert_eq!(indexes(AtlasIndexes, &frames, 3), [0, 7, 22]);
+    }
+
+    #[test]
+    fn animations_use_their_sheet_indexing() {
+        let animation = TimedAnimation::new(
+            vec![0, 1, 2],
+            vec![0.1; 3],
+            grid(),
+            AnimationDirectionIndexes::default(),
+            LoopMode::Once,
+            false,
+            0,
+        );
+        assert_eq!(animation.sheet_indexing.atlas_index(1, 2, &grid()), 9);
+        let animation = animation.with_sheet_indexing(ColumnMajor);
+        assert_eq!(animation.sheet_indexing.atlas_index(1, 2, &grid()), 6);
+    }
+}
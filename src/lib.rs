@@ -1,17 +1,36 @@
 use std::{
     collections::HashMap,
-    sync::{Arc, Mutex},
+    sync::{atomic::AtomicBool, Arc, Mutex},
     time::Duration,
 };
 
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
 mod animations;
+mod cursor;
+mod deprecated;
+mod importers;
+mod indexing;
+mod library;
+mod looping;
+mod playback;
 mod plugins;
+mod state_machine;
+mod timing;
 mod types;
 
 pub use animations::*;
+pub use cursor::*;
+pub use deprecated::*;
+pub use importers::*;
+pub use indexing::*;
+pub use library::*;
+pub use looping::*;
+pub use playback::*;
 pub use plugins::*;
+pub use state_machine::*;
+pub use timing::*;
 
 pub use types::*;
 pub mod prelude {
@@ -19,11 +38,26 @@ pub mod prelude {
         LinearTimedAnimation, LinearTransformAnimation, SingleFrameAnimation, TimedAnimation,
         TransformAnimation,
     };
-    pub use crate::plugins::AnimationsPlugin;
+    pub use crate::cursor::AnimationCursor;
+    pub use crate::indexing::{
+        AtlasIndexes, ColumnMajor, IndexTable, RowMajor, SheetIndexer, SheetIndexing, WrappedRows,
+    };
+    pub use crate::library::AnimationLibrary;
+    pub use crate::looping::LoopMode;
+    pub use crate::playback::AnimationPlayback;
+    pub use crate::plugins::{AnimationSystems, AnimationsPlugin};
+    pub use crate::state_machine::{
+        AnimationCondition, AnimationParam, AnimationStateGraph, AnimationStateGraphLoader,
+        AnimationStateMachine, AnimationTransition,
+    };
+    pub use crate::timing::AnimationTimeSource;
     pub use crate::types::{
-        Animation, AnimationDirection, AnimationDirectionIndexes, AnimationEvent, AnimationName,
-        AnimationType, Animator, FXAnimationEvent, FXBasedDirection, FlipBasedDirection,
-        IndexBasedDirection, NewAnimation, ResetAnimationEvent, YIndex,
+        Animation, AnimationDirection, AnimationDirectionIndexes, AnimationEvent,
+        AnimationFinished, AnimationFrameEvent, AnimationInterrupted, AnimationLooped,
+        AnimationName, AnimationSeek, AnimationSlice, AnimationSpeed, AnimationStarted,
+        AnimationTimeScale, AnimationType, Animator, FXAnimationEvent, FXBasedDirection,
+        FlipBasedDirection, IndexBasedDirection, NewAnimation, PauseAnimation, ResetAnimationEvent,
+        ResumeAnimation, SeekAnimation, SheetGrid, StopAnimation, YIndex,
     };
     pub use crate::{Animations, AnimationsConfig};
 }
@@ -31,27 +65,66 @@ pub mod prelude {
 #[derive(Component, Clone)]
 struct FXAnimation;
 
+/// Added to sprites showing a frame with a [FramePivot] so they can go back to how they were without one
+#[derive(Component, Clone, Copy, Debug, Default)]
+struct PivotedFrame {
+    /// The anchor the sprite had before it showed a frame with a pivot
+    original_anchor: Vec2,
+    /// If the frame shown is rotated in its sprite sheet
+    rotated: bool,
+    /// The rotation of the [Transform] without the frame's rotation. Set while the frame's rotation was part of the last
+    /// propagated [GlobalTransform]
+    original_rotation: Option<Quat>,
+    /// If the sprite shows a frame without a pivot again and got its own anchor back
+    restored: bool,
+}
+
+/// What an [AnimationLibrary] inserted into [Animations] the last time it was loaded
+#[derive(Debug, Default)]
+struct LibraryEntries {
+    animations: Vec<AnimationName>,
+    fx_animations: Vec<AnimationName>,
+    layouts: Vec<AssetId<TextureAtlasLayout>>,
+}
+
 #[derive(Debug, Resource, Default)]
 pub struct AnimationsConfig {
     pixels_per_meter: f32,
+    time_source: AnimationTimeSource,
 }
 
-#[derive(Debug, Resource, Default)]
-pub struct EntitesToRemove(Vec<Entity>);
-
 #[derive(Component, Deref, DerefMut, Clone, Debug, Default)]
 pub struct AnimationTimer(pub Timer);
 
-#[derive(Debug)]
-pub struct AnimatingEntity {
-    pub entity: Entity,
-    pub in_blocking_animation: bool,
-    pub animations: HashMap<AnimationName, Arc<Mutex<AnimationType>>>,
-    pub curr_animation: Arc<Mutex<AnimationType>>,
-    pub curr_direction: AnimationDirection,
-    pub last_valid_direction: AnimationDirection,
-    pub curr_animation_called: bool,
-    pub fx_animation: bool,
+impl AnimationTimer {
+    /// Adds `delta` to the time built up for the current frame. Unlike [Timer::tick] it doesn't wrap around
+    /// so a frame that got more time than it needed can pass the rest on with [AnimationTimer::take_frame]
+    pub(crate) fn accumulate(&mut self, delta: Duration) {
+        if self.is_paused() {
+            return;
+        }
+        let elapsed = self.elapsed() + delta;
+        self.set_elapsed(elapsed);
+    }
+
+    /// Takes the duration of the current frame out of the built up time
+    ///
+    /// Returns `false` if not enough time has built up yet
+    pub(crate) fn take_frame(&mut self) -> bool {
+        let Some(left_over) = self.elapsed().checked_sub(self.duration()) else {
+            return false;
+        };
+        self.set_elapsed(left_over);
+        true
+    }
+
+    /// Drops the time built up past the duration of the current frame
+    pub(crate) fn clamp_to_duration(&mut self) {
+        if self.elapsed() > self.duration() {
+            let duration = self.duration();
+            self.set_elapsed(duration);
+        }
+    }
 }
 
 #[derive(Default, Debug, Bundle)]
@@ -62,127 +135,130 @@ pub struct SpriteBundle {
 
 #[derive(Default, Resource, Debug)]
 pub struct Animations {
-    entities: HashMap<Entity, AnimatingEntity>,
     animations: HashMap<AnimationName, Animation>,
     fx_animations: HashMap<AnimationName, Animation>,
+    playback_changes: Vec<PlaybackChange>,
+    /// A copy of every [AnimationPlayback] from the end of the last update, only kept for the deprecated entity methods
+    entities: HashMap<Entity, AnimationPlayback>,
+    mirror_entities: AtomicBool,
+    frame_pivots: HashMap<AssetId<TextureAtlasLayout>, Vec<FramePivot>>,
+    libraries: HashMap<AssetId<AnimationLibrary>, LibraryEntries>,
 }
 
 impl Animations {
     /// Adds a new animation to the animation pool.
     ///
-    /// Can optionally add an entity to the animation.
+    /// Can optionally add an entity to the animation. The entity gets its [AnimationPlayback] at the start of the next [AnimationSystems::Input]
     ///
-    /// # Panics
-    /// When an animation with the same name already exists
+    /// If an animation with the same name already exists it is reloaded via [Animations::reload_animation]
     pub fn insert_animation(
         &mut self,
         animation: NewAnimation,
         entity: Option<Entity>,
     ) -> &mut Self {
         let name = animation.animation.get_name();
-        let animation = Animation {
-            handles: animation.handles,
-            animation: Arc::new(Mutex::new(animation.animation)),
-        };
-        let new_animation = Arc::clone(&animation.animation);
-        let animation = animation;
-        if self.animations.get_mut(&name).is_none() {
+        if self.animations.contains_key(name) {
+            self.reload_animation(animation)
+                .expect("Something Went Terribly Wrong Reloading Animation");
+        } else {
+            let animation = Animation {
+                handles: animation.handles,
+                animation: Arc::new(animation.animation),
+            };
             self.animations.insert(name, animation);
         }
         if let Some(entity) = entity {
-            if let Some(animating_entity) = self.entities.get_mut(&entity) {
-                animating_entity
-                    .animations
-                    .insert(name, Arc::clone(&new_animation));
-            } else {
-                let mut map = HashMap::new();
-                map.insert(name, Arc::clone(&new_animation));
-                self.entities.insert(
-                    entity,
-                    AnimatingEntity {
-                        entity,
-                        animations: map,
-                        curr_animation: new_animation,
-                        curr_direction: AnimationDirection::Still,
-                        last_valid_direction: AnimationDirection::default(),
-                        in_blocking_animation: false,
-                        curr_animation_called: false,
-                        fx_animation: false,
-                    },
-                );
-            }
+            self.add_animation_to_entity(name, entity)
+                .expect("Something Went Terribly Wrong Adding Animation To Entity");
         }
         self
     }
 
-    /// Add an [Entity] to the pool without a current animation specified
+    /// Replaces an animation in the pool and on every entity that has it without respawning anything
+    ///
+    /// Each entity keeps its current direction and, if it still exists in the new definition, its current frame.
+    /// Entities currently playing the animation are pointed at the new sprite sheet if the [Handles] changed
+    ///
+    /// Returns [Result<(), String>] an [Err(String)] if the animation doesn't exist in the pool
+    pub fn reload_animation(&mut self, animation: NewAnimation) -> Result<(), String> {
+        let name = animation.animation.get_name();
+        let Some(pool_animation) = self.animations.get_mut(name) else {
+            return Err(format!("Animation {} doesn't exist in `Animations`", name));
+        };
+        let handles_changed = pool_animation.handles != animation.handles;
+        pool_animation.handles = animation.handles.clone();
+        pool_animation.animation = Arc::new(animation.animation);
+        self.playback_changes.push(PlaybackChange::Reload {
+            animation: Arc::clone(&pool_animation.animation),
+            handles: animation.handles,
+            handles_changed,
+            fx_animation: false,
+        });
+        Ok(())
+    }
+
+    /// Replaces an FX animation in the pool and on every FX currently playing it
     ///
-    /// Returns [Result<(), String>] an [Err(String)] if the entity already exists in the pool
-    pub fn insert_entity(&mut self, entity: Entity) -> Result<(), String> {
-        if self.entities.contains_key(&entity) {
+    /// Returns [Result<(), String>] an [Err(String)] if the FX animation doesn't exist in the pool
+    pub fn reload_fx_animation(&mut self, animation: NewAnimation) -> Result<(), String> {
+        let name = animation.animation.get_name();
+        let Some(pool_animation) = self.fx_animations.get_mut(name) else {
             return Err(format!(
-                "Entity {:?} already exists in `Animations`",
-                entity
+                "FX Animation {} doesn't exist in `Animations`",
+                name
             ));
-        }
-        self.entities.insert(
-            entity,
-            AnimatingEntity {
-                entity,
-                animations: HashMap::new(),
-                curr_animation: Arc::new(Mutex::new(AnimationType::default())),
-                curr_direction: AnimationDirection::Still,
-                last_valid_direction: AnimationDirection::default(),
-                in_blocking_animation: false,
-                curr_animation_called: false,
-                fx_animation: false,
-            },
-        );
+        };
+        let handles_changed = pool_animation.handles != animation.handles;
+        pool_animation.handles = animation.handles.clone();
+        pool_animation.animation = Arc::new(animation.animation);
+        self.playback_changes.push(PlaybackChange::Reload {
+            animation: Arc::clone(&pool_animation.animation),
+            handles: animation.handles,
+            handles_changed,
+            fx_animation: true,
+        });
         Ok(())
     }
 
-    /// Add an animation to an [Entity]
+    /// Takes the changes to entities that were made since the last time they were applied
+    pub(crate) fn take_playback_changes(&mut self) -> Vec<PlaybackChange> {
+        std::mem::take(&mut self.playback_changes)
+    }
+
+    /// Add an animation in the pool to an [Entity]
+    ///
+    /// Every entity shares the same definition of the animation and plays it with its own [AnimationCursor]. The animation is added at the start of the next [AnimationSystems::Input]. If the entity already has it nothing changes
     ///
-    /// Returns [Result<(), String>] an [Err(String)] if the animation already exists on the entity specified
+    /// An [AnimationEvent] for the animation sent before it's added starts it as soon as it is
+    ///
+    /// Returns [Result<(), String>] an [Err(String)] if the animation doesn't exist in the pool
     pub fn add_animation_to_entity(
         &mut self,
         animation_name: AnimationName,
         entity: Entity,
     ) -> Result<(), String> {
-        if let Some(animation) = self.animations.get_mut(&animation_name) {
-            let ac_animation = Arc::new(Mutex::new(animation.animation.lock().unwrap().clone()));
-            if let Some(entity) = self.entities.get_mut(&entity) {
-                if entity.animations.contains_key(&animation_name) {
-                    return Err(format!(
-                        "Animation {:?} already exists on entity {:?}",
-                        animation_name, entity.entity
-                    ));
-                }
-                if entity.curr_animation.lock().unwrap().is_none() {
-                    entity.curr_animation = Arc::clone(&ac_animation);
-                }
-                entity.animations.insert(animation_name, ac_animation);
-            } else {
-                let mut map = HashMap::new();
-                map.insert(animation_name, Arc::clone(&ac_animation));
-                self.entities.insert(
-                    entity,
-                    AnimatingEntity {
-                        entity,
-                        animations: map,
-                        curr_animation: ac_animation,
-                        curr_direction: AnimationDirection::Still,
-                        last_valid_direction: AnimationDirection::default(),
-                        in_blocking_animation: false,
-                        curr_animation_called: false,
-                        fx_animation: false,
-                    },
-                );
-            }
-        }
+        let Some(animation) = self.animations.get(animation_name) else {
+            return Err(format!(
+                "Animation {} doesn't exist in `Animations`",
+                animation_name
+            ));
+        };
+        let animation = Arc::clone(&animation.animation);
+        self.playback_changes.push(PlaybackChange::Attach {
+            entity,
+            name: animation_name,
+            animation,
+        });
         Ok(())
     }
 
+    /// If the animation is waiting to be added to the entity at the start of the next [AnimationSystems::Input]
+    pub(crate) fn is_attaching(&self, animation_name: AnimationName, entity: Entity) -> bool {
+        self.playback_changes.iter().any(|change| {
+            matches!(change, PlaybackChange::Attach { entity: attached, name, .. } if *attached == entity && *name == animation_name)
+        })
+    }
+
     /// Gets a clone of the `TextureAtlasLayout` and `Image` handle for the animation specified
     pub fn get_handles(&self, animation_name: AnimationName) -> Option<Handles> {
         if let Some(animation) = self.animations.get(&animation_name) {
@@ -201,79 +277,85 @@ impl Animations {
         None
     }
 
-    /// Gets the animating entity from the entity specified
-    ///
-    /// Returns [None] if the entity does not exist in the pool
-    pub fn get_entity(&mut self, entity: &Entity) -> Option<&mut AnimatingEntity> {
-        if let Some(animating_entity) = self.entities.get_mut(entity) {
-            return Some(animating_entity);
-        }
-        None
-    }
-
-    /// Checks if the animation specified is not animating on the entity specified currently
-    ///
-    /// Returns [None] if the entity does not exist in the pool
-    pub fn is_new_animation(&self, animation_name: AnimationName, entity: &Entity) -> Option<bool> {
-        if let Some(animating_entity) = self.entities.get(entity) {
-            if animating_entity.curr_animation.lock().unwrap().get_name() != animation_name {
-                return Some(true);
-            }
-            return Some(false);
-        }
-        None
-    }
-
-    /// If the entity specified exists in the pool
-    pub fn has_entity(&self, entity: &Entity) -> bool {
-        if self.entities.contains_key(entity) {
-            return true;
-        }
-        false
-    }
-
     /// Insert an FX animation this. In order to start the FX animation send it through an [EventWriter(FXAnimationEvent(AnimationName))]
+    ///
+    /// If an FX animation with the same name already exists it is reloaded via [Animations::reload_fx_animation]
     pub fn insert_fx_animation(&mut self, value: NewAnimation) -> &mut Self {
         let key = value.animation.get_name();
         if self.fx_animations.contains_key(key) {
+            self.reload_fx_animation(value)
+                .expect("Something Went Terribly Wrong Reloading FX Animation");
             self
         } else {
             let animation = Animation {
                 handles: value.handles,
-                animation: Arc::new(Mutex::new(value.animation)),
+                animation: Arc::new(value.animation),
             };
             self.fx_animations.insert(key, animation);
             self
         }
     }
 
-    /// Add an FX animation to a new [AnimatingEntity]. This will start the animation specified.
+    /// Adds an animation named `name` that plays part of the animation `source`, optionally backwards, with the same sprite sheet
+    ///
+    /// If `source` is an FX animation the new animation is an FX animation too
+    ///
+    /// Returns [Result<(), String>] an [Err(String)] if `source` doesn't exist in the pool or the frames of `slice` don't exist
+    pub fn insert_sliced_animation(
+        &mut self,
+        name: AnimationName,
+        source: AnimationName,
+        slice: AnimationSlice,
+    ) -> Result<(), String> {
+        let (source_animation, fx_animation) = match self.animations.get(source) {
+            Some(animation) => (animation, false),
+            None => match self.fx_animations.get(source) {
+                Some(animation) => (animation, true),
+                None => {
+                    return Err(format!(
+                        "Animation {} doesn't exist in `Animations`",
+                        source
+                    ))
+                }
+            },
+        };
+        let animation = NewAnimation {
+            handles: source_animation.handles.clone(),
+            animation: source_animation.animation.slice(name, &slice)?,
+        };
+        if fx_animation {
+            self.insert_fx_animation(animation);
+        } else {
+            self.insert_animation(animation, None);
+        }
+        Ok(())
+    }
+
+    /// Makes the sprite and [AnimationPlayback] of a new FX entity. This will start the animation specified.
     ///
     /// # Note
     ///
     /// This method is used for the backend and shouldn't be called directly. If you need to start an fx animation use [FXAnimationEvent] instead.
     pub fn start_fx_animation(
-        &mut self,
-        key: Entity,
+        &self,
         animation: AnimationName,
         pos: Vec3,
-    ) -> Option<SpriteBundle> {
+    ) -> Option<(SpriteBundle, AnimationPlayback)> {
         let name = animation;
-        let Some(animation) = self.fx_animations.get(animation) else {
-            return None;
-        };
-        let mut animation = animation.animation.lock().unwrap().clone();
+        let animation = self.fx_animations.get(animation)?;
+        let animation = Arc::clone(&animation.animation);
+        let mut cursor = animation.cursor();
 
         let index = if let Some(timed_animation) = animation.timed_animation() {
-            timed_animation.sprite_index(&AnimationDirection::default())
+            timed_animation.sprite_index(&mut cursor, &AnimationDirection::default())
         } else if let Some(transform_animation) = animation.transform_animation() {
-            transform_animation.sprite_index(&AnimationDirection::default())
+            transform_animation.sprite_index(&mut cursor, &AnimationDirection::default())
         } else if let Some(linear_timed_animation) = animation.linear_timed_animation() {
-            linear_timed_animation.sprite_index(&AnimationDirection::default())
+            linear_timed_animation.sprite_index(&mut cursor, &AnimationDirection::default())
         } else if let Some(linear_transform_animation) = animation.linear_transform_animation() {
-            linear_transform_animation.sprite_index(&AnimationDirection::default())
+            linear_transform_animation.sprite_index(&mut cursor, &AnimationDirection::default())
         } else if let Some(single_frame_animation) = animation.single_frame_animation() {
-            single_frame_animation.sprite_index(&AnimationDirection::default())
+            single_frame_animation.sprite_index(&cursor, &AnimationDirection::default())
         } else {
             panic!("Something Went Terribly Wrong Starting FX Animation");
         };
@@ -286,28 +368,24 @@ impl Animations {
             layout: handles.layout().clone(),
             index,
         };
-        // Add the animation and entity to a new AnimatingEntity to be animated
-        self.entities.insert(
-            key,
-            AnimatingEntity {
-                entity: key,
-                in_blocking_animation: false,
-                animations: HashMap::new(),
-                curr_animation: Arc::new(Mutex::new(animation)),
-                curr_direction: AnimationDirection::default(),
-                last_valid_direction: AnimationDirection::default(),
-                curr_animation_called: true,
-                fx_animation: true,
-            },
-        );
-        Some(SpriteBundle {
+        // The FX starts playing the animation straight away with its own cursor
+        let playback = AnimationPlayback {
+            curr_animation: animation,
+            cursor,
+            curr_direction: AnimationDirection::default(),
+            curr_animation_called: true,
+            fx_animation: true,
+            ..Default::default()
+        };
+        let sprite_bundle = SpriteBundle {
             transform: Transform::from_translation(pos),
             sprite: Sprite {
                 image: handles.image().clone(),
                 texture_atlas: Some(texture_atlas),
                 ..Default::default()
             },
-        })
+        };
+        Some((sprite_bundle, playback))
         // Ok(SpriteSheetBundle {
         //     atlas,
         //     transform: Transform::from_translation(pos),
@@ -315,82 +393,202 @@ impl Animations {
         // })
     }
 
-    /// if the animation exists in the pool
-    pub fn has_animation(&self, animation_name: AnimationName) -> bool {
-        if self.animations.contains_key(animation_name) {
-            return true;
-        }
-        false
+    /// Sets the [FramePivot] of every frame in the layout specified. The pivot at index `i` is used for atlas index `i`
+    ///
+    /// Inserting pivots for a layout that already has them replaces them
+    pub fn insert_frame_pivots(
+        &mut self,
+        layout: &Handle<TextureAtlasLayout>,
+        pivots: Vec<FramePivot>,
+    ) -> &mut Self {
+        self.frame_pivots.insert(layout.id(), pivots);
+        self
     }
 
-    /// Returns [Some(())] if the animation already exists on the entity specified
-    ///
-    /// Returns [None] if the entity was not found in the pool
+    /// Inserts every animation of a library that finished loading
     ///
-    /// Returns [None] if the animation was not found on the entity specified
-    pub fn entity_has_animation(
-        &self,
-        animation_name: &AnimationName,
-        entity: Entity,
-    ) -> Option<()> {
-        if let Some(animating_entity) = self.entities.get(&entity) {
-            if animating_entity.animations.contains_key(animation_name) {
-                return Some(());
+    /// When the library was inserted before, the animations it still has are reloaded and the ones removed from it are removed from the pool
+    pub(crate) fn insert_library(
+        &mut self,
+        id: AssetId<AnimationLibrary>,
+        library: &AnimationLibrary,
+    ) {
+        let previous = self.libraries.remove(&id).unwrap_or_default();
+        let entries = LibraryEntries {
+            animations: library
+                .animations
+                .iter()
+                .map(|animation| animation.animation.get_name())
+                .collect(),
+            fx_animations: library
+                .fx_animations
+                .iter()
+                .map(|animation| animation.animation.get_name())
+                .collect(),
+            layouts: library
+                .frame_pivots
+                .iter()
+                .map(|(layout, _)| layout.id())
+                .collect(),
+        };
+        self.libraries.insert(id, entries);
+        // Another library can still have an animation this one doesn't have anymore
+        for name in previous.animations {
+            if !self
+                .libraries
+                .values()
+                .any(|entries| entries.animations.contains(&name))
+            {
+                self.animations.remove(name);
+            }
+        }
+        for name in previous.fx_animations {
+            if !self
+                .libraries
+                .values()
+                .any(|entries| entries.fx_animations.contains(&name))
+            {
+                self.fx_animations.remove(name);
+            }
+        }
+        for layout in previous.layouts {
+            if !self
+                .libraries
+                .values()
+                .any(|entries| entries.layouts.contains(&layout))
+            {
+                self.frame_pivots.remove(&layout);
             }
-            return None;
         }
-        None
-    }
-
-    /// Returns [Some(bool)] if the entity exists and [Some(true)] if the entity is in a blocking animation
-    ///
-    /// Returns [None] if the entity was not found
-    ///
-    /// usefull to determine for example whether or not to move an entity
-    pub fn in_blocking_animation(&self, entity: Entity) -> Option<bool> {
-        self.entities
-            .get(&entity)
-            .map(|animating_entity| animating_entity.in_blocking_animation)
-    }
 
-    /// Returns [Some(bool)] if the entity exists and [Some(true)] if the entity is in an animation
-    ///
-    /// Returns [None] if the entity was not found
-    ///
-    /// useful for determining for example whether or not to initate another animation
-    pub fn in_animation(&self, entity: Entity) -> Option<bool> {
-        self.entities
-            .get(&entity)
-            .map(|animating_entity| animating_entity.curr_animation_called)
+        for animation in library.animations.iter() {
+            self.insert_animation(animation.clone(), None);
+        }
+        for fx_animation in library.fx_animations.iter() {
+            self.insert_fx_animation(fx_animation.clone());
+        }
+        for (layout, pivots) in library.frame_pivots.iter() {
+            self.insert_frame_pivots(layout, pivots.clone());
+        }
     }
 
-    /// Returns [Some(bool)] if the entity exists and [Some(true)] if the entity is in the animation specified
-    ///
-    /// Returns [None] if the entity was not found
+    /// Gets the [FramePivot] for the atlas index of the layout specified
     ///
-    /// useful for determining for example whether or not to initate another animation
-    pub fn doing_animation(&self, entity: Entity, animation_name: AnimationName) -> Option<bool> {
-        self.entities.get(&entity).map(|animating_entity| {
-            animating_entity.curr_animation_called
-                && animating_entity.curr_animation.lock().unwrap().get_name() == animation_name
-        })
+    /// Returns [None] if the layout has no pivots
+    pub fn get_frame_pivot(
+        &self,
+        layout: AssetId<TextureAtlasLayout>,
+        index: usize,
+    ) -> Option<&FramePivot> {
+        self.frame_pivots
+            .get(&layout)
+            .and_then(|pivots| pivots.get(index))
     }
 
-    /// Returns `true` if the [Entity] exists in the [Animations] map
-    pub fn is_inserted(&self, key: &Entity) -> bool {
-        if self.entities.contains_key(key) {
+    /// if the animation exists in the pool
+    pub fn has_animation(&self, animation_name: AnimationName) -> bool {
+        if self.animations.contains_key(animation_name) {
             return true;
         }
         false
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    /// Mainly for debug purposes to see the map. Not reccomended to change item.
-    pub fn get_mut_map(&mut self) -> &mut HashMap<Entity, AnimatingEntity> {
-        &mut self.entities
+    fn library(names: &[AnimationName]) -> AnimationLibrary {
+        AnimationLibrary {
+            animations: names
+                .iter()
+                .map(|name| NewAnimation {
+                    handles: Handles::default(),
+                    animation: AnimationType::LinearTimed(
+                        LinearTimedAnimation::new(vec![0, 1], vec![0.1; 2], LoopMode::Loop),
+                        name,
+                    ),
+                })
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn reloading_a_library_removes_deleted_animations() {
+        let mut animations = Animations::default();
+        let id = AssetId::<AnimationLibrary>::default();
+        let other_id = AssetId::<AnimationLibrary>::Uuid {
+            uuid: bevy::asset::uuid::Uuid::from_u128(1),
+        };
+        animations.insert_library(id, &library(&["idle", "walk", "shared"]));
+        animations.insert_library(other_id, &library(&["shared"]));
+        animations.insert_library(id, &library(&["idle"]));
+        assert!(animations.has_animation("idle"));
+        assert!(!animations.has_animation("walk"));
+        // Still in the other library
+        assert!(animations.has_animation("shared"));
     }
 
-    /// Mainly for debug purposes to see the map. Not reccomended to change item.
-    pub fn get_map(&self) -> &HashMap<Entity, AnimatingEntity> {
-        &self.entities
+    fn walk(frames: usize, timing: f32, loop_mode: LoopMode) -> NewAnimation {
+        NewAnimation {
+            handles: Handles::default(),
+            animation: AnimationType::LinearTimed(
+                LinearTimedAnimation::new((0..frames).collect(), vec![timing; frames], loop_mode),
+                "walk",
+            ),
+        }
+    }
+
+    #[test]
+    fn reloading_an_animation_retimes_the_current_frame() {
+        let mut world = World::new();
+        world.init_resource::<Animations>();
+        let entity = world.spawn_empty().id();
+        world
+            .resource_mut::<Animations>()
+            .insert_animation(walk(4, 0.5, LoopMode::HoldLastFrame), Some(entity));
+        apply_playback_changes(&mut world);
+        {
+            let mut playback = world.get_mut::<AnimationPlayback>(entity).unwrap();
+            let cursor = &mut playback.cursor;
+            cursor.animation_tick = 2;
+            cursor.frame_shown = true;
+            cursor
+                .animation_timer
+                .accumulate(Duration::from_secs_f32(0.4));
+            cursor.progress.advance(LoopMode::HoldLastFrame, 3, 4);
+            cursor.progress.finish(LoopMode::HoldLastFrame);
+        }
+
+        // The frame was already shown longer than its new timing, so it moves on with the next update
+        world
+            .resource_mut::<Animations>()
+            .reload_animation(walk(4, 0.25, LoopMode::Loop))
+            .unwrap();
+        apply_playback_changes(&mut world);
+        let cursor = world.get::<AnimationPlayback>(entity).unwrap().cursor();
+        assert_eq!(cursor.animation_tick, 2);
+        assert_eq!(
+            cursor.animation_timer.duration(),
+            Duration::from_secs_f32(0.25)
+        );
+        assert_eq!(
+            cursor.animation_timer.elapsed(),
+            Duration::from_secs_f32(0.25)
+        );
+        // Holding the last frame doesn't make sense for a looping animation
+        assert!(!cursor.is_holding());
+
+        // The current frame is gone so the animation starts over
+        world
+            .resource_mut::<Animations>()
+            .reload_animation(walk(2, 0.25, LoopMode::Loop))
+            .unwrap();
+        apply_playback_changes(&mut world);
+        let cursor = world.get::<AnimationPlayback>(entity).unwrap().cursor();
+        assert_eq!(cursor.animation_tick, 0);
+        assert!(!cursor.frame_shown);
+        assert_eq!(cursor.animation_timer.elapsed(), Duration::ZERO);
     }
 }
@@ -0,0 +1,1145 @@
+use std::collections::BTreeSet;
+
+use bevy::asset::{
+    io::Reader, AssetLoader, AssetPath, AssetServerMode, LoadContext, ParseAssetPathError,
+};
+use bevy::tasks::futures_lite::StreamExt;
+
+use crate::*;
+
+/// A collection of animations defined in a `.anim.ron` or `.anim.json` file
+///
+/// Once the library and its sprite sheets are loaded every animation in it is inserted into the [Animations] pool
+/// so it can be started with an [AnimationEvent] or [FXAnimationEvent] like any other animation.
+///
+/// **Note** the [Handle] returned by the `AssetServer` needs to be kept alive or the library will be unloaded
+///
+/// # Example
+/// ```ron
+/// (
+///     image: "player.png",
+///     grid: (cell_size: (16, 16), columns: 8, rows: 4),
+///     animations: [
+///         (
+///             name: "player_running",
+///             kind: Timed(
+///                 animation_frames: [0, 1, 2, 3],
+///                 frame_timings_in_secs: [0.1, 0.1, 0.1, 0.1],
+///                 direction_indexes: FlipBased((left_direction_is_flipped: true, x_direction_index: 3)),
+///                 repeating: true,
+///             ),
+///         ),
+///     ],
+///     fx_animations: [],
+/// )
+/// ```
+#[derive(Asset, TypePath, Debug, Default)]
+pub struct AnimationLibrary {
+    /// Animations that will be inserted into the animation pool
+    pub animations: Vec<NewAnimation>,
+    /// Animations that will be inserted into the FX animation pool
+    pub fx_animations: Vec<NewAnimation>,
+    /// The pivots of packed sprite sheets that trim or rotate their frames
+    pub frame_pivots: Vec<(Handle<TextureAtlasLayout>, Vec<FramePivot>)>,
+    /// The animations of a Tiled tileset keyed by the id of the tile they animate
+    pub tile_animations: HashMap<u32, AnimationName>,
+}
+
+impl AnimationLibrary {
+    /// Gets the name of the animation for the tile id specified when the library is a Tiled tileset
+    ///
+    /// Returns [None] if the tile isn't animated
+    pub fn tile_animation(&self, tile_id: u32) -> Option<AnimationName> {
+        self.tile_animations.get(&tile_id).copied()
+    }
+}
+
+/// Loads an [AnimationLibrary] from RON or JSON depending on the file extension
+#[derive(TypePath)]
+pub struct AnimationLibraryLoader {
+    /// Lists the folders of frame images, which a [LoadContext] can't do
+    asset_server: AssetServer,
+}
+
+impl FromWorld for AnimationLibraryLoader {
+    fn from_world(world: &mut World) -> Self {
+        Self {
+            asset_server: world.resource::<AssetServer>().clone(),
+        }
+    }
+}
+
+impl AssetLoader for AnimationLibraryLoader {
+    type Asset = AnimationLibrary;
+    type Settings = ();
+    type Error = AnimationLibraryError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<AnimationLibrary, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let is_json = load_context
+            .path()
+            .get_full_extension()
+            .is_some_and(|extension| extension.ends_with("json"));
+        let mut definition: LibraryDefinition = if is_json {
+            serde_json::from_slice(&bytes)?
+        } else {
+            from_ron(&bytes)?
+        };
+        definition
+            .list_frame_folders(&self.asset_server, load_context.path())
+            .await?;
+        definition.into_library(load_context).await
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["anim.ron", "anim.json"]
+    }
+}
+
+/// Parses RON allowing optional fields to be written without `Some(...)`
+pub(crate) fn from_ron<T: serde::de::DeserializeOwned>(
+    bytes: &[u8],
+) -> Result<T, AnimationLibraryError> {
+    Ok(ron::Options::default()
+        .with_default_extension(ron::extensions::Extensions::IMPLICIT_SOME)
+        .from_bytes(bytes)?)
+}
+
+/// Everything that can go wrong loading an [AnimationLibrary]
+#[derive(Debug)]
+pub enum AnimationLibraryError {
+    /// The file couldn't be read
+    Io(std::io::Error),
+    /// The file isn't valid RON
+    Ron(ron::error::SpannedError),
+    /// The file isn't valid JSON
+    Json(serde_json::Error),
+    /// The file isn't valid XML
+    Xml(roxmltree::Error),
+    /// The file isn't a valid GIF
+    Gif(gif::DecodingError),
+    /// A sheet image path couldn't be resolved
+    Path(ParseAssetPathError),
+    /// An animation definition is incomplete or inconsistent
+    InvalidAnimation(String),
+}
+
+impl std::fmt::Display for AnimationLibraryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnimationLibraryError::Io(err) => write!(f, "Could not read animation library: {err}"),
+            AnimationLibraryError::Ron(err) => write!(f, "Invalid RON animation library: {err}"),
+            AnimationLibraryError::Json(err) => {
+                write!(f, "Invalid JSON animation library: {err}")
+            }
+            AnimationLibraryError::Xml(err) => write!(f, "Invalid XML animation library: {err}"),
+            AnimationLibraryError::Gif(err) => write!(f, "Invalid GIF: {err}"),
+            AnimationLibraryError::Path(err) => write!(f, "Invalid sheet image path: {err}"),
+            AnimationLibraryError::InvalidAnimation(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for AnimationLibraryError {}
+
+impl From<std::io::Error> for AnimationLibraryError {
+    fn from(value: std::io::Error) -> Self {
+        Self::Io(value)
+    }
+}
+
+impl From<ron::error::SpannedError> for AnimationLibraryError {
+    fn from(value: ron::error::SpannedError) -> Self {
+        Self::Ron(value)
+    }
+}
+
+impl From<serde_json::Error> for AnimationLibraryError {
+    fn from(value: serde_json::Error) -> Self {
+        Self::Json(value)
+    }
+}
+
+impl From<roxmltree::Error> for AnimationLibraryError {
+    fn from(value: roxmltree::Error) -> Self {
+        Self::Xml(value)
+    }
+}
+
+impl From<gif::DecodingError> for AnimationLibraryError {
+    fn from(value: gif::DecodingError) -> Self {
+        Self::Gif(value)
+    }
+}
+
+impl From<ParseAssetPathError> for AnimationLibraryError {
+    fn from(value: ParseAssetPathError) -> Self {
+        Self::Path(value)
+    }
+}
+
+/// Turns a name read at runtime into an [AnimationName]
+///
+/// [AnimationName] is a `&'static str` so the name has to outlive every asset using it.
+/// Every distinct name is leaked exactly once and never freed, even after the libraries using it are unloaded.
+/// Reloading or loading the same library again doesn't leak more memory, but generating new names forever would
+pub fn intern_animation_name(name: &str) -> AnimationName {
+    static NAMES: Mutex<BTreeSet<&'static str>> = Mutex::new(BTreeSet::new());
+
+    let mut names = NAMES.lock().unwrap();
+    if let Some(interned) = names.get(name) {
+        return interned;
+    }
+    let interned: &'static str = Box::leak(name.to_owned().into_boxed_str());
+    names.insert(interned);
+    interned
+}
+
+/// The top level of an animation library file
+#[derive(Debug, Deserialize)]
+struct LibraryDefinition {
+    /// The sprite sheet used by every animation that doesn't specify its own. Relative to the library file
+    #[serde(default)]
+    image: Option<String>,
+    /// The grid of the sprite sheet used by every animation that doesn't specify its own
+    #[serde(default)]
+    grid: Option<GridDefinition>,
+    #[serde(default)]
+    animations: Vec<AnimationDefinition>,
+    #[serde(default)]
+    fx_animations: Vec<AnimationDefinition>,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+struct GridDefinition {
+    cell_size: (u32, u32),
+    columns: u32,
+    rows: u32,
+    #[serde(default)]
+    padding: Option<(u32, u32)>,
+    #[serde(default)]
+    offset: Option<(u32, u32)>,
+}
+
+impl From<GridDefinition> for SheetGrid {
+    fn from(grid: GridDefinition) -> Self {
+        SheetGrid {
+            cell_size: grid.cell_size.into(),
+            columns: grid.columns,
+            rows: grid.rows,
+            padding: grid.padding.map(UVec2::from),
+            offset: grid.offset.map(UVec2::from),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct AnimationDefinition {
+    name: String,
+    /// Overrides the library's sprite sheet for this animation
+    #[serde(default)]
+    image: Option<String>,
+    /// Overrides the library's grid for this animation
+    #[serde(default)]
+    grid: Option<GridDefinition>,
+    /// Individual frame images to pack into a sprite sheet instead of using `image` and `grid`
+    #[serde(default)]
+    frame_images: Option<FrameImages>,
+    kind: AnimationKindDefinition,
+}
+
+/// Loose frame images relative to the library file
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum FrameImages {
+    /// A numbered sequence like `"run_{01..12}.png"`. The range is inclusive and keeps the zero padding of its start
+    ///
+    /// A folder like `"run/"` is every image in it ordered by file name, see [LibraryDefinition::list_frame_folders]
+    Sequence(String),
+    /// Every frame image in order
+    List(Vec<String>),
+}
+
+impl FrameImages {
+    /// The folder of the frame images if the sequence ends with a `/`
+    fn folder(&self) -> Option<&str> {
+        match self {
+            FrameImages::Sequence(pattern) if pattern.ends_with('/') => Some(pattern),
+            _ => None,
+        }
+    }
+
+    fn paths(self) -> Result<Vec<String>, AnimationLibraryError> {
+        let pattern = match self {
+            FrameImages::List(paths) => return Ok(paths),
+            FrameImages::Sequence(pattern) => pattern,
+        };
+        let Some((prefix, rest)) = pattern.split_once('{') else {
+            return Ok(vec![pattern]);
+        };
+        let invalid = || {
+            AnimationLibraryError::InvalidAnimation(format!(
+                "Frame image sequence `{pattern}` should look like `run_{{01..12}}.png`"
+            ))
+        };
+        let (range, suffix) = rest.split_once('}').ok_or_else(invalid)?;
+        let (start, end) = range.split_once("..").ok_or_else(invalid)?;
+        let width = start.len();
+        let start: usize = start.parse().map_err(|_| invalid())?;
+        let end: usize = end.parse().map_err(|_| invalid())?;
+        Ok((start..=end)
+            .map(|number| format!("{prefix}{number:0width$}{suffix}"))
+            .collect())
+    }
+}
+
+/// Mirrors the constructors of each animation type
+#[derive(Debug, Deserialize)]
+enum AnimationKindDefinition {
+    Timed {
+        #[serde(default)]
+        animation_frames: Vec<FrameDefinition>,
+        #[serde(default)]
+        frame_timings_in_secs: Vec<f32>,
+        /// Replaces `frame_timings_in_secs` with timings in ticks of `Time<Fixed>`
+        #[serde(default)]
+        frame_timings_in_ticks: Option<Vec<u32>>,
+        #[serde(default)]
+        time_source: Option<AnimationTimeSource>,
+        #[serde(default)]
+        direction_indexes: AnimationDirectionIndexes,
+        #[serde(default)]
+        repeating: bool,
+        #[serde(default)]
+        loop_mode: Option<LoopMode>,
+        #[serde(default)]
+        blocking: bool,
+        #[serde(default)]
+        blocking_priority: i32,
+        #[serde(default)]
+        sheet_indexing: SheetIndexingDefinition,
+        #[serde(default)]
+        frame_markers: Vec<(usize, String)>,
+    },
+    Transform {
+        #[serde(default)]
+        animation_frames: Vec<FrameDefinition>,
+        meters_per_frame: f32,
+        #[serde(default)]
+        direction_indexes: AnimationDirectionIndexes,
+        #[serde(default)]
+        repeating: bool,
+        #[serde(default)]
+        loop_mode: Option<LoopMode>,
+        #[serde(default)]
+        sheet_indexing: SheetIndexingDefinition,
+    },
+    LinearTimed {
+        #[serde(default)]
+        animation_frames: Vec<FrameDefinition>,
+        #[serde(default)]
+        frame_timings_in_secs: Vec<f32>,
+        /// Replaces `frame_timings_in_secs` with timings in ticks of `Time<Fixed>`
+        #[serde(default)]
+        frame_timings_in_ticks: Option<Vec<u32>>,
+        #[serde(default)]
+        time_source: Option<AnimationTimeSource>,
+        #[serde(default)]
+        repeating: bool,
+        #[serde(default)]
+        loop_mode: Option<LoopMode>,
+        #[serde(default)]
+        frame_markers: Vec<(usize, String)>,
+    },
+    LinearTransform {
+        #[serde(default)]
+        animation_frames: Vec<FrameDefinition>,
+        meters_per_frame: f32,
+        #[serde(default)]
+        repeating: bool,
+        #[serde(default)]
+        loop_mode: Option<LoopMode>,
+    },
+    /// Plays part of another animation of the same library, see [AnimationSlice]
+    Slice {
+        /// The name of the animation to play part of. It needs to come before the slice in the file
+        of: String,
+        /// The `(start, end)` positions in `animation_frames` to play, `end` not included
+        #[serde(default)]
+        frames: Option<(usize, usize)>,
+        #[serde(default)]
+        reverse: bool,
+        #[serde(default)]
+        loop_mode: Option<LoopMode>,
+    },
+    SingleFrame {
+        x_index_pos: usize,
+        #[serde(default)]
+        direction_indexes: AnimationDirectionIndexes,
+        #[serde(default)]
+        blocking: bool,
+        #[serde(default)]
+        blocking_priority: i32,
+        #[serde(default)]
+        blocking_duration_in_sec: f32,
+        #[serde(default)]
+        sheet_indexing: SheetIndexingDefinition,
+        #[serde(default)]
+        time_source: Option<AnimationTimeSource>,
+    },
+}
+
+/// A frame in `animation_frames`
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(untagged)]
+enum FrameDefinition {
+    Index(usize),
+    /// A `(column, row)` cell of the grid
+    Cell(usize, usize),
+}
+
+/// Converts `frames` to the values the animation expects
+///
+/// If any frame is a `(column, row)` cell every frame is an atlas index so the animation has to use [AtlasIndexes]
+fn frame_indexes(frames: Vec<FrameDefinition>, grid: &SheetGrid) -> (Vec<usize>, bool) {
+    let uses_cells = frames
+        .iter()
+        .any(|frame| matches!(frame, FrameDefinition::Cell(..)));
+    let frames = frames
+        .into_iter()
+        .map(|frame| match frame {
+            FrameDefinition::Index(index) => index,
+            FrameDefinition::Cell(x, y) => grid.index(x, y),
+        })
+        .collect();
+    (frames, uses_cells)
+}
+
+fn intern_frame_markers(frame_markers: Vec<(usize, String)>) -> Vec<(usize, &'static str)> {
+    frame_markers
+        .into_iter()
+        .map(|(frame, marker)| (frame, intern_animation_name(&marker)))
+        .collect()
+}
+
+/// The built-in [SheetIndexing]s
+#[derive(Debug, Default, Deserialize)]
+enum SheetIndexingDefinition {
+    #[default]
+    RowMajor,
+    ColumnMajor,
+    WrappedRows(usize),
+    IndexTable(Vec<Vec<usize>>),
+    AtlasIndexes,
+}
+
+impl SheetIndexingDefinition {
+    fn into_sheet_indexer(self) -> SheetIndexer {
+        match self {
+            SheetIndexingDefinition::RowMajor => SheetIndexer::new(RowMajor),
+            SheetIndexingDefinition::ColumnMajor => SheetIndexer::new(ColumnMajor),
+            SheetIndexingDefinition::WrappedRows(rows_per_direction) => {
+                SheetIndexer::new(WrappedRows { rows_per_direction })
+            }
+            SheetIndexingDefinition::IndexTable(table) => SheetIndexer::new(IndexTable(table)),
+            SheetIndexingDefinition::AtlasIndexes => SheetIndexer::new(AtlasIndexes),
+        }
+    }
+}
+
+impl LibraryDefinition {
+    /// Replaces every folder of frame images with a list of the images in it, ordered by file name
+    async fn list_frame_folders(
+        &mut self,
+        asset_server: &AssetServer,
+        library_path: &AssetPath<'static>,
+    ) -> Result<(), AnimationLibraryError> {
+        for definition in self
+            .animations
+            .iter_mut()
+            .chain(self.fx_animations.iter_mut())
+        {
+            let Some(folder) = definition
+                .frame_images
+                .as_ref()
+                .and_then(FrameImages::folder)
+            else {
+                continue;
+            };
+            let unreadable = |err: String| {
+                AnimationLibraryError::InvalidAnimation(format!(
+                    "Could not read the frame image folder `{folder}` of animation `{}`: {err}",
+                    definition.name
+                ))
+            };
+            let folder_path = library_path.resolve_embed(folder)?;
+            let source = asset_server
+                .get_source(folder_path.source())
+                .map_err(|err| unreadable(err.to_string()))?;
+            let reader = match asset_server.mode() {
+                AssetServerMode::Unprocessed => source.reader(),
+                AssetServerMode::Processed => source
+                    .processed_reader()
+                    .map_err(|err| unreadable(err.to_string()))?,
+            };
+            let mut entries = reader
+                .read_directory(folder_path.path())
+                .await
+                .map_err(|err| unreadable(err.to_string()))?;
+            let mut paths = Vec::new();
+            while let Some(entry) = entries.next().await {
+                // Asset meta files and nested folders aren't frames
+                let is_meta = entry
+                    .extension()
+                    .is_some_and(|extension| extension == "meta");
+                if is_meta || reader.is_directory(&entry).await.unwrap_or(false) {
+                    continue;
+                }
+                if let Some(file_name) = entry.file_name().and_then(|name| name.to_str()) {
+                    paths.push(format!("{folder}{file_name}"));
+                }
+            }
+            if paths.is_empty() {
+                return Err(unreadable("it has no images".to_string()));
+            }
+            paths.sort();
+            definition.frame_images = Some(FrameImages::List(paths));
+        }
+        Ok(())
+    }
+
+    async fn into_library(
+        self,
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<AnimationLibrary, AnimationLibraryError> {
+        let mut library = AnimationLibrary::default();
+        let mut layouts = HashMap::new();
+        for definition in self.animations {
+            let animation = match definition.kind {
+                AnimationKindDefinition::Slice { .. } => {
+                    definition.into_slice(&library.animations)?
+                }
+                _ => {
+                    definition
+                        .into_new_animation(
+                            "animations",
+                            &self.image,
+                            &self.grid,
+                            &mut layouts,
+                            load_context,
+                        )
+                        .await?
+                }
+            };
+            library.animations.push(animation);
+        }
+        for definition in self.fx_animations {
+            let animation = match definition.kind {
+                AnimationKindDefinition::Slice { .. } => {
+                    definition.into_slice(&library.fx_animations)?
+                }
+                _ => {
+                    definition
+                        .into_new_animation(
+                            "fx_animations",
+                            &self.image,
+                            &self.grid,
+                            &mut layouts,
+                            load_context,
+                        )
+                        .await?
+                }
+            };
+            library.fx_animations.push(animation);
+        }
+        Ok(library)
+    }
+}
+
+impl AnimationDefinition {
+    /// Slices an animation defined earlier in the same list
+    fn into_slice(
+        self,
+        animations: &[NewAnimation],
+    ) -> Result<NewAnimation, AnimationLibraryError> {
+        let AnimationKindDefinition::Slice {
+            of,
+            frames,
+            reverse,
+            loop_mode,
+        } = self.kind
+        else {
+            unreachable!("Only slices are turned into slices");
+        };
+        let Some(source) = animations
+            .iter()
+            .find(|animation| animation.animation.get_name() == of)
+        else {
+            return Err(AnimationLibraryError::InvalidAnimation(format!(
+                "Animation `{}` slices `{of}` which isn't defined before it",
+                self.name
+            )));
+        };
+        let slice = AnimationSlice {
+            frames: frames.map(|(start, end)| start..end),
+            reverse,
+            loop_mode,
+        };
+        let animation = source
+            .animation
+            .slice(intern_animation_name(&self.name), &slice)
+            .map_err(|err| {
+                AnimationLibraryError::InvalidAnimation(format!("Animation `{}`: {err}", self.name))
+            })?;
+        Ok(NewAnimation {
+            handles: source.handles.clone(),
+            animation,
+        })
+    }
+
+    /// `list` is the library list the animation is defined in, labeled assets of both lists can't share names
+    async fn into_new_animation(
+        mut self,
+        list: &str,
+        library_image: &Option<String>,
+        library_grid: &Option<GridDefinition>,
+        layouts: &mut HashMap<String, Handle<TextureAtlasLayout>>,
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<NewAnimation, AnimationLibraryError> {
+        let name = intern_animation_name(&self.name);
+        if let Some(frame_images) = self.frame_images.take() {
+            let (handles, frame_count) = self
+                .pack_frame_images(list, frame_images, load_context)
+                .await?;
+            self.kind.use_packed_frames(frame_count);
+            let animation = self
+                .kind
+                .into_animation_type(name, SheetGrid::from(Vec2::new(frame_count as f32, 1.)))?;
+            return Ok(NewAnimation { handles, animation });
+        }
+
+        let Some(image) = self.image.as_ref().or(library_image.as_ref()) else {
+            return Err(AnimationLibraryError::InvalidAnimation(format!(
+                "Animation `{}` has no sheet image and the library doesn't define one",
+                self.name
+            )));
+        };
+        let (grid, label) = match (self.grid, library_grid) {
+            (Some(grid), _) => (
+                SheetGrid::from(grid),
+                format!("{list}/{}/layout", self.name),
+            ),
+            (None, Some(grid)) => (SheetGrid::from(*grid), "layout".to_string()),
+            (None, None) => {
+                return Err(AnimationLibraryError::InvalidAnimation(format!(
+                    "Animation `{}` has no grid and the library doesn't define one",
+                    self.name
+                )))
+            }
+        };
+
+        let image_path = load_context.path().resolve_embed(image)?;
+        let image = load_context.load::<Image>(image_path);
+        // Animations sharing a grid share a single layout
+        let layout = layouts
+            .entry(label.clone())
+            .or_insert_with(|| load_context.add_labeled_asset(label, grid.layout()))
+            .clone();
+
+        let animation = self.kind.into_animation_type(name, grid)?;
+        Ok(NewAnimation {
+            handles: Handles::new(image, layout),
+            animation,
+        })
+    }
+
+    /// Loads every frame image and packs them into a single sprite sheet in order
+    ///
+    /// Returns the [Handles] of the sheet and the number of frames in it
+    async fn pack_frame_images(
+        &self,
+        list: &str,
+        frame_images: FrameImages,
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<(Handles, usize), AnimationLibraryError> {
+        let mut images = Vec::new();
+        for path in frame_images.paths()? {
+            let image_path = load_context.path().resolve_embed(&path)?;
+            let image = load_context
+                .loader()
+                .immediate()
+                .load::<Image>(image_path)
+                .await
+                .map_err(|err| {
+                    AnimationLibraryError::InvalidAnimation(format!(
+                        "Could not load frame `{path}` of animation `{}`: {err}",
+                        self.name
+                    ))
+                })?;
+            images.push(image.take());
+        }
+
+        let mut builder = TextureAtlasBuilder::default();
+        for image in images.iter() {
+            builder.add_texture(None, image);
+        }
+        let (layout, _, image) = builder.build().map_err(|err| {
+            AnimationLibraryError::InvalidAnimation(format!(
+                "Could not pack the frames of animation `{}`: {err}",
+                self.name
+            ))
+        })?;
+        let handles = Handles::new(
+            load_context.add_labeled_asset(format!("{list}/{}/image", self.name), image),
+            load_context.add_labeled_asset(format!("{list}/{}/layout", self.name), layout),
+        );
+        Ok((handles, images.len()))
+    }
+}
+
+impl AnimationKindDefinition {
+    /// Points the animation at a sheet packed from `frame_count` loose frame images
+    ///
+    /// `animation_frames` index into the frame images and default to every frame in order.
+    /// A packed sheet only has one row so the direction indexes can't pick a row anymore
+    fn use_packed_frames(&mut self, frame_count: usize) {
+        let single_row = |direction_indexes: &mut AnimationDirectionIndexes| {
+            *direction_indexes = match *direction_indexes {
+                AnimationDirectionIndexes::FlipBased(flip_based) => {
+                    AnimationDirectionIndexes::FlipBased(FlipBasedDirection {
+                        x_direction_index: 0,
+                        ..flip_based
+                    })
+                }
+                _ => AnimationDirectionIndexes::FX(FXBasedDirection { index: 0 }),
+            }
+        };
+        match self {
+            AnimationKindDefinition::Timed {
+                animation_frames,
+                direction_indexes,
+                sheet_indexing,
+                ..
+            }
+            | AnimationKindDefinition::Transform {
+                animation_frames,
+                direction_indexes,
+                sheet_indexing,
+                ..
+            } => {
+                if animation_frames.is_empty() {
+                    *animation_frames = (0..frame_count).map(FrameDefinition::Index).collect();
+                }
+                single_row(direction_indexes);
+                *sheet_indexing = SheetIndexingDefinition::RowMajor;
+            }
+            AnimationKindDefinition::LinearTimed {
+                animation_frames, ..
+            }
+            | AnimationKindDefinition::LinearTransform {
+                animation_frames, ..
+            } => {
+                if animation_frames.is_empty() {
+                    *animation_frames = (0..frame_count).map(FrameDefinition::Index).collect();
+                }
+            }
+            AnimationKindDefinition::SingleFrame {
+                direction_indexes,
+                sheet_indexing,
+                ..
+            } => {
+                single_row(direction_indexes);
+                *sheet_indexing = SheetIndexingDefinition::RowMajor;
+            }
+            // Slices use the sprite sheet of the animation they slice
+            AnimationKindDefinition::Slice { .. } => {}
+        }
+    }
+
+    fn into_animation_type(
+        self,
+        name: AnimationName,
+        frame: SheetGrid,
+    ) -> Result<AnimationType, AnimationLibraryError> {
+        let check_timings = |frames: &Vec<usize>, timings: usize| {
+            if timings == 0 || frames.len() != timings {
+                return Err(AnimationLibraryError::InvalidAnimation(format!(
+                    "Animation `{name}` has {} frames but {} timings",
+                    frames.len(),
+                    timings
+                )));
+            }
+            Ok(())
+        };
+        // An animation timed in ticks doesn't use its seconds so they can be left out
+        let timings_in_secs =
+            |frames: &Vec<usize>, secs: Vec<f32>, ticks: &Option<Vec<u32>>| match ticks {
+                Some(ticks) => check_timings(frames, ticks.len()).map(|_| Vec::new()),
+                None => check_timings(frames, secs.len()).map(|_| secs),
+            };
+        match &self {
+            AnimationKindDefinition::Timed {
+                animation_frames, ..
+            }
+            | AnimationKindDefinition::Transform {
+                animation_frames, ..
+            }
+            | AnimationKindDefinition::LinearTimed {
+                animation_frames, ..
+            }
+            | AnimationKindDefinition::LinearTransform {
+                animation_frames, ..
+            } if animation_frames.is_empty() => {
+                return Err(AnimationLibraryError::InvalidAnimation(format!(
+                    "Animation `{name}` has no animation_frames"
+                )));
+            }
+            _ => {}
+        }
+        let animation = match self {
+            AnimationKindDefinition::Timed {
+                animation_frames,
+                frame_timings_in_secs,
+                frame_timings_in_ticks,
+                time_source,
+                direction_indexes,
+                repeating,
+                loop_mode,
+                blocking,
+                blocking_priority,
+                sheet_indexing,
+                frame_markers,
+            } => {
+                let (animation_frames, uses_cells) = frame_indexes(animation_frames, &frame);
+                let frame_timings_in_secs = timings_in_secs(
+                    &animation_frames,
+                    frame_timings_in_secs,
+                    &frame_timings_in_ticks,
+                )?;
+                let mut animation = TimedAnimation::new(
+                    animation_frames,
+                    frame_timings_in_secs,
+                    frame,
+                    direction_indexes,
+                    loop_mode.unwrap_or(repeating.into()),
+                    blocking,
+                    blocking_priority,
+                );
+                animation.sheet_indexing = match uses_cells {
+                    true => SheetIndexer::new(AtlasIndexes),
+                    false => sheet_indexing.into_sheet_indexer(),
+                };
+                animation.frame_markers = intern_frame_markers(frame_markers);
+                animation.time_source = time_source;
+                if let Some(frame_timings_in_ticks) = frame_timings_in_ticks {
+                    animation = animation.with_tick_timings(frame_timings_in_ticks);
+                }
+                AnimationType::Timed(animation, name)
+            }
+            AnimationKindDefinition::Transform {
+                animation_frames,
+                meters_per_frame,
+                direction_indexes,
+                repeating,
+                loop_mode,
+                sheet_indexing,
+            } => {
+                let (animation_frames, uses_cells) = frame_indexes(animation_frames, &frame);
+                let mut animation = TransformAnimation::new(
+                    animation_frames,
+                    meters_per_frame,
+                    frame,
+                    direction_indexes,
+                    loop_mode.unwrap_or(repeating.into()),
+                );
+                animation.sheet_indexing = match uses_cells {
+                    true => SheetIndexer::new(AtlasIndexes),
+                    false => sheet_indexing.into_sheet_indexer(),
+                };
+                AnimationType::Transform(animation, name)
+            }
+            AnimationKindDefinition::LinearTimed {
+                animation_frames,
+                frame_timings_in_secs,
+                frame_timings_in_ticks,
+                time_source,
+                repeating,
+                loop_mode,
+                frame_markers,
+            } => {
+                let (animation_frames, _) = frame_indexes(animation_frames, &frame);
+                let frame_timings_in_secs = timings_in_secs(
+                    &animation_frames,
+                    frame_timings_in_secs,
+                    &frame_timings_in_ticks,
+                )?;
+                let mut animation = LinearTimedAnimation::new(
+                    animation_frames,
+                    frame_timings_in_secs,
+                    loop_mode.unwrap_or(repeating.into()),
+                );
+                animation.frame_markers = intern_frame_markers(frame_markers);
+                animation.time_source = time_source;
+                if let Some(frame_timings_in_ticks) = frame_timings_in_ticks {
+                    animation = animation.with_tick_timings(frame_timings_in_ticks);
+                }
+                AnimationType::LinearTimed(animation, name)
+            }
+            AnimationKindDefinition::LinearTransform {
+                animation_frames,
+                meters_per_frame,
+                repeating,
+                loop_mode,
+            } => {
+                let (animation_frames, _) = frame_indexes(animation_frames, &frame);
+                AnimationType::LinearTransform(
+                    LinearTransformAnimation::new(
+                        animation_frames,
+                        meters_per_frame,
+                        loop_mode.unwrap_or(repeating.into()),
+                    ),
+                    name,
+                )
+            }
+            AnimationKindDefinition::SingleFrame {
+                x_index_pos,
+                direction_indexes,
+                blocking,
+                blocking_priority,
+                blocking_duration_in_sec,
+                sheet_indexing,
+                time_source,
+            } => {
+                let mut animation = SingleFrameAnimation::new(
+                    x_index_pos,
+                    direction_indexes,
+                    blocking,
+                    blocking_priority,
+                    blocking_duration_in_sec,
+                    frame,
+                );
+                animation.sheet_indexing = sheet_indexing.into_sheet_indexer();
+                animation.time_source = time_source;
+                AnimationType::SingleFrame(animation, name)
+            }
+            AnimationKindDefinition::Slice { of, .. } => {
+                return Err(AnimationLibraryError::InvalidAnimation(format!(
+                    "Animation `{name}` slices `{of}` so it can't have its own sprite sheet"
+                )));
+            }
+        };
+        Ok(animation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frame_images(ron: &str) -> FrameImages {
+        from_ron(ron.as_bytes()).unwrap()
+    }
+
+    #[test]
+    fn sequences_keep_the_zero_padding_of_their_start() {
+        assert_eq!(
+            frame_images(r#""run_{08..10}.png""#).paths().unwrap(),
+            vec!["run_08.png", "run_09.png", "run_10.png"]
+        );
+        assert_eq!(
+            frame_images(r#""player/run{1..3}""#).paths().unwrap(),
+            vec!["player/run1", "player/run2", "player/run3"]
+        );
+        assert_eq!(
+            frame_images(r#""idle.png""#).paths().unwrap(),
+            vec!["idle.png"]
+        );
+    }
+
+    #[test]
+    fn lists_are_used_as_they_are() {
+        let images = frame_images(r#"["idle.png", "idle_blink.png"]"#);
+        assert!(images.folder().is_none());
+        assert_eq!(images.paths().unwrap(), vec!["idle.png", "idle_blink.png"]);
+    }
+
+    #[test]
+    fn broken_sequences_are_rejected() {
+        for pattern in [
+            r#""run_{01..}.png""#,
+            r#""run_{a..3}.png""#,
+            r#""run_{01-12}.png""#,
+            r#""run_{01..12.png""#,
+        ] {
+            assert!(frame_images(pattern).paths().is_err(), "{pattern}");
+        }
+    }
+
+    #[test]
+    fn folders_end_with_a_slash() {
+        assert_eq!(
+            frame_images(r#""player/run/""#).folder(),
+            Some("player/run/")
+        );
+        assert!(frame_images(r#""player/run""#).folder().is_none());
+    }
+
+    const LIBRARY_RON: &str = r#"(
+        image: "player.png",
+        grid: (cell_size: (16, 16), columns: 8, rows: 4),
+        animations: [
+            (
+                name: "player_running",
+                kind: Timed(
+                    animation_frames: [0, 1, (2, 1)],
+                    frame_timings_in_secs: [0.1, 0.1, 0.2],
+                    loop_mode: PingPong,
+                    frame_markers: [(1, "footstep")],
+                ),
+            ),
+            (
+                name: "player_start",
+                kind: Slice(of: "player_running", frames: (0, 2)),
+            ),
+        ],
+        fx_animations: [
+            (
+                name: "dust",
+                grid: (cell_size: (8, 8), columns: 4, rows: 1),
+                frame_images: "dust_{1..4}.png",
+                kind: LinearTimed(frame_timings_in_ticks: [2, 2, 2, 2]),
+            ),
+        ],
+    )"#;
+
+    const LIBRARY_JSON: &str = r#"{
+        "image": "door.png",
+        "grid": { "cell_size": [32, 32], "columns": 4, "rows": 1 },
+        "animations": [
+            {
+                "name": "door_open",
+                "kind": {
+                    "LinearTimed": {
+                        "animation_frames": [0, 1, 2, 3],
+                        "frame_timings_in_secs": [0.1, 0.1, 0.1, 0.1]
+                    }
+                }
+            }
+        ]
+    }"#;
+
+    fn grid() -> SheetGrid {
+        SheetGrid::from(GridDefinition {
+            cell_size: (16, 16),
+            columns: 8,
+            rows: 4,
+            padding: None,
+            offset: None,
+        })
+    }
+
+    fn kind(ron: &str) -> AnimationKindDefinition {
+        from_ron(ron.as_bytes()).unwrap()
+    }
+
+    fn invalid_message(ron: &str) -> String {
+        match kind(ron).into_animation_type("broken", grid()) {
+            Err(AnimationLibraryError::InvalidAnimation(message)) => message,
+            result => panic!("`{ron}` should be an invalid animation, got {result:?}"),
+        }
+    }
+
+    #[test]
+    fn libraries_are_read_from_ron() {
+        let library: LibraryDefinition = from_ron(LIBRARY_RON.as_bytes()).unwrap();
+        assert_eq!(library.image.as_deref(), Some("player.png"));
+        assert_eq!(library.grid.unwrap().columns, 8);
+        assert_eq!(library.animations.len(), 2);
+        assert!(matches!(
+            library.animations[1].kind,
+            AnimationKindDefinition::Slice {
+                frames: Some((0, 2)),
+                ..
+            }
+        ));
+
+        let dust = &library.fx_animations[0];
+        assert_eq!(dust.grid.unwrap().cell_size, (8, 8));
+        assert!(matches!(dust.frame_images, Some(FrameImages::Sequence(_))));
+
+        let running = library.animations.into_iter().next().unwrap();
+        let AnimationType::Timed(animation, name) = running
+            .kind
+            .into_animation_type("player_running", grid())
+            .unwrap()
+        else {
+            panic!("player_running should be timed");
+        };
+        assert_eq!(name, "player_running");
+        // A single `(column, row)` cell turns every frame into an atlas index
+        assert_eq!(animation.animation_frames, vec![0, 1, 10]);
+        assert_eq!(animation.frame_timings_in_secs, vec![0.1, 0.1, 0.2]);
+        assert_eq!(animation.loop_mode, LoopMode::PingPong);
+        assert_eq!(animation.frame_markers, vec![(1, "footstep")]);
+    }
+
+    #[test]
+    fn libraries_are_read_from_json() {
+        let library: LibraryDefinition = serde_json::from_str(LIBRARY_JSON).unwrap();
+        assert_eq!(library.image.as_deref(), Some("door.png"));
+        assert!(library.fx_animations.is_empty());
+
+        let door = library.animations.into_iter().next().unwrap();
+        let AnimationType::LinearTimed(animation, _) = door
+            .kind
+            .into_animation_type("door_open", library.grid.unwrap().into())
+            .unwrap()
+        else {
+            panic!("door_open should be linear timed");
+        };
+        assert_eq!(animation.animation_frames, vec![0, 1, 2, 3]);
+        assert_eq!(animation.loop_mode, LoopMode::Once);
+    }
+
+    #[test]
+    fn tick_timed_animations_leave_out_their_seconds() {
+        let AnimationType::LinearTimed(animation, _) = kind(
+            "LinearTimed(animation_frames: [0, 1], frame_timings_in_ticks: [3, 4], repeating: true)",
+        )
+        .into_animation_type("ticks", grid())
+        .unwrap() else {
+            panic!("ticks should be linear timed");
+        };
+        assert!(animation.frame_timings_in_secs.is_empty());
+        assert_eq!(animation.frame_timings_in_ticks, vec![3, 4]);
+        assert_eq!(animation.loop_mode, LoopMode::Loop);
+    }
+
+    #[test]
+    fn inconsistent_animations_are_rejected() {
+        assert_eq!(
+            invalid_message(
+                "Timed(animation_frames: [0, 1, 2], frame_timings_in_secs: [0.1, 0.1])"
+            ),
+            "Animation `broken` has 3 frames but 2 timings"
+        );
+        assert_eq!(
+            invalid_message("LinearTimed(animation_frames: [0, 1])"),
+            "Animation `broken` has 2 frames but 0 timings"
+        );
+        assert_eq!(
+            invalid_message("Timed(animation_frames: [0, 1], frame_timings_in_ticks: [1])"),
+            "Animation `broken` has 2 frames but 1 timings"
+        );
+        assert_eq!(
+            invalid_message("LinearTransform(meters_per_frame: 1.0)"),
+            "Animation `broken` has no animation_frames"
+        );
+    }
+}
@@ -0,0 +1,235 @@
+use crate::*;
+
+/// What an animation does once it plays its last frame
+///
+/// `true` and `false` convert into [LoopMode::Loop] and [LoopMode::Once] so the `repeating` argument of the animation constructors still works
+///
+/// # Example
+/// ```rust
+/// let breathing = TimedAnimation::new(
+///     Vec::from(IDLE_FRAMES),
+///     Vec::from(IDLE_TIMINGS),
+///     Vec2::new(14., 38.),
+///     AnimationDirectionIndexes::FX(FXBasedDirection { index: 0 }),
+///     LoopMode::PingPong, /* loop_mode */
+///     false,
+///     0,
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LoopMode {
+    /// Plays the frames once. Starting the animation again plays it from the first frame
+    #[default]
+    Once,
+    /// Starts over from the first frame forever
+    Loop,
+    /// Plays the frames this many times in total and then finishes
+    ///
+    /// `LoopCount(0)` plays the frames once like [LoopMode::Once] since there's no playing them zero times
+    LoopCount(usize),
+    /// Plays the frames forwards then backwards forever
+    PingPong,
+    /// Plays the frames forwards then backwards this many times in total and then finishes on the first frame
+    ///
+    /// `PingPongCount(0)` plays them forwards and backwards once like `PingPongCount(1)`
+    PingPongCount(usize),
+    /// Plays the frames once and stays on the last frame until another animation starts
+    HoldLastFrame,
+}
+
+impl From<bool> for LoopMode {
+    fn from(repeating: bool) -> Self {
+        match repeating {
+            true => LoopMode::Loop,
+            false => LoopMode::Once,
+        }
+    }
+}
+
+impl LoopMode {
+    /// If the animation never finishes on its own
+    pub fn is_endless(&self) -> bool {
+        matches!(self, LoopMode::Loop | LoopMode::PingPong)
+    }
+}
+
+/// Where an animation is in its [LoopMode]
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct LoopProgress {
+    loops: usize,
+    backwards: bool,
+    starting_over: bool,
+    looped: bool,
+    ended: bool,
+    holding: bool,
+}
+
+impl LoopProgress {
+    /// The position in `animation_frames` to show after `position` for an animation with `frame_count` frames
+    ///
+    /// When there is nothing left to show `position` is returned and the progress is [ended](Self::ended)
+    pub(crate) fn advance(&mut self, mode: LoopMode, position: usize, frame_count: usize) -> usize {
+        let last = frame_count.saturating_sub(1);
+        if matches!(mode, LoopMode::PingPong | LoopMode::PingPongCount(_)) && last > 0 {
+            return self.ping_pong(mode, position, last);
+        }
+        if position < last {
+            return position + 1;
+        }
+        match mode {
+            LoopMode::Once | LoopMode::HoldLastFrame => self.end(position),
+            LoopMode::LoopCount(count) | LoopMode::PingPongCount(count)
+                if self.loops + 1 >= count =>
+            {
+                self.end(position)
+            }
+            _ => self.start_over(0),
+        }
+    }
+
+    fn ping_pong(&mut self, mode: LoopMode, position: usize, last: usize) -> usize {
+        if position == last {
+            self.backwards = true;
+        }
+        if !self.backwards {
+            return position + 1;
+        }
+        let last_loop = matches!(mode, LoopMode::PingPongCount(count) if self.loops + 1 >= count);
+        match position {
+            0 if last_loop => self.end(0),
+            0 => {
+                self.backwards = false;
+                1
+            }
+            // Getting back to the first frame is when a ping pong starts over
+            1 if !last_loop => {
+                self.backwards = false;
+                self.start_over(0)
+            }
+            _ => position - 1,
+        }
+    }
+
+    fn start_over(&mut self, position: usize) -> usize {
+        self.loops += 1;
+        self.starting_over = true;
+        position
+    }
+
+    fn end(&mut self, position: usize) -> usize {
+        self.ended = true;
+        position
+    }
+
+    /// Called every time a frame is shown so starting over is only counted once the first frame shows again
+    pub(crate) fn frame_shown(&mut self) {
+        if std::mem::take(&mut self.starting_over) {
+            self.looped = true;
+        }
+    }
+
+    /// If the animation has no frames left to show
+    pub(crate) fn ended(&self) -> bool {
+        self.ended
+    }
+
+    /// Finishes an [ended](Self::ended) animation. Returns [None] the first time it's called
+    ///
+    /// A [LoopMode::HoldLastFrame] animation keeps returning [Some(())] afterwards so it stays on its last frame,
+    /// every other animation is reset so it plays from the start the next time
+    pub(crate) fn finish(&mut self, mode: LoopMode) -> Option<()> {
+        if mode != LoopMode::HoldLastFrame {
+            *self = Self::default();
+            return None;
+        }
+        if self.holding {
+            return Some(());
+        }
+        self.holding = true;
+        None
+    }
+
+    /// If the animation finished and is holding its last frame
+    pub(crate) fn is_holding(&self) -> bool {
+        self.holding
+    }
+
+    /// Takes if the animation started over since the last time this was called
+    pub(crate) fn take_looped(&mut self) -> bool {
+        std::mem::take(&mut self.looped)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The positions an animation with `frame_count` frames shows until it ends or `limit` frames were shown,
+    /// and how many times it started over
+    fn play(mode: LoopMode, frame_count: usize, limit: usize) -> (Vec<usize>, usize) {
+        let mut progress = LoopProgress::default();
+        let mut position = 0;
+        let mut shown = Vec::new();
+        let mut loops = 0;
+        while !progress.ended() && shown.len() < limit {
+            shown.push(position);
+            progress.frame_shown();
+            if progress.take_looped() {
+                loops += 1;
+            }
+            position = progress.advance(mode, position, frame_count);
+        }
+        (shown, loops)
+    }
+
+    #[test]
+    fn once_ends_after_the_last_frame() {
+        assert_eq!(play(LoopMode::Once, 3, 10), (vec![0, 1, 2], 0));
+        assert_eq!(play(LoopMode::Once, 1, 10), (vec![0], 0));
+    }
+
+    #[test]
+    fn loop_count_plays_the_frames_that_many_times() {
+        assert_eq!(
+            play(LoopMode::LoopCount(3), 2, 10),
+            (vec![0, 1, 0, 1, 0, 1], 2)
+        );
+        assert_eq!(play(LoopMode::LoopCount(1), 3, 10), (vec![0, 1, 2], 0));
+        // There's no playing the frames zero times
+        assert_eq!(play(LoopMode::LoopCount(0), 3, 10), (vec![0, 1, 2], 0));
+    }
+
+    #[test]
+    fn loop_never_ends() {
+        assert_eq!(
+            play(LoopMode::Loop, 3, 8),
+            (vec![0, 1, 2, 0, 1, 2, 0, 1], 2)
+        );
+        assert!(LoopMode::Loop.is_endless());
+        assert!(!LoopMode::LoopCount(3).is_endless());
+    }
+
+    #[test]
+    fn ping_pong_count_finishes_on_the_first_frame() {
+        assert_eq!(
+            play(LoopMode::PingPongCount(2), 3, 20),
+            (vec![0, 1, 2, 1, 0, 1, 2, 1, 0], 1)
+        );
+    }
+
+    #[test]
+    fn finishing_resets_unless_holding_the_last_frame() {
+        let (mut progress, mut position) = (LoopProgress::default(), 0);
+        while !progress.ended() {
+            position = progress.advance(LoopMode::Once, position, 2);
+        }
+        assert_eq!(position, 1);
+        assert_eq!(progress.finish(LoopMode::Once), None);
+        assert!(!progress.ended());
+
+        progress.advance(LoopMode::HoldLastFrame, 1, 2);
+        assert_eq!(progress.finish(LoopMode::HoldLastFrame), None);
+        assert!(progress.is_holding());
+        assert_eq!(progress.finish(LoopMode::HoldLastFrame), Some(()));
+    }
+}
@@ -0,0 +1,252 @@
+use bevy::ecs::{lifecycle::HookContext, world::DeferredWorld};
+
+use crate::*;
+
+/// The animations of an entity and what it's currently playing
+///
+/// Added along with the [Animator] so you can query it like any other component
+///
+/// # Example
+/// ```rust
+/// fn move_player(
+///     mut query: Query<(&mut Transform, &AnimationPlayback), With<Player>>,
+/// ) {
+///     let (mut transform, playback) = query.single_mut().unwrap();
+///     // don't move while attacking
+///     if playback.in_blocking_animation() {
+///         return;
+///     }
+///     /* you move logic here... */
+/// }
+/// ```
+#[derive(Component, Debug, Clone)]
+pub struct AnimationPlayback {
+    pub(crate) in_blocking_animation: bool,
+    pub(crate) animations: HashMap<AnimationName, Arc<AnimationType>>,
+    pub(crate) curr_animation: Arc<AnimationType>,
+    /// Where the entity is in `curr_animation`
+    pub(crate) cursor: AnimationCursor,
+    pub(crate) curr_direction: AnimationDirection,
+    pub(crate) last_valid_direction: AnimationDirection,
+    pub(crate) curr_animation_called: bool,
+    pub(crate) fx_animation: bool,
+    /// If the current animation is frozen by a [PauseAnimation]
+    pub(crate) paused: bool,
+    /// The atlas index to show when the current animation moved to another frame without being cycled
+    pub(crate) pending_index: Option<usize>,
+}
+
+impl Default for AnimationPlayback {
+    fn default() -> Self {
+        Self {
+            in_blocking_animation: false,
+            animations: HashMap::new(),
+            curr_animation: Arc::new(AnimationType::default()),
+            cursor: AnimationCursor::default(),
+            curr_direction: AnimationDirection::Still,
+            last_valid_direction: AnimationDirection::default(),
+            curr_animation_called: false,
+            fx_animation: false,
+            paused: false,
+            pending_index: None,
+        }
+    }
+}
+
+impl AnimationPlayback {
+    /// Adds `animation` to the entity unless it already has an animation named `name`. The first animation added becomes the current one
+    pub(crate) fn attach(&mut self, name: AnimationName, animation: Arc<AnimationType>) {
+        if self.animations.contains_key(name) {
+            return;
+        }
+        if self.curr_animation.is_none() {
+            self.cursor = animation.cursor();
+            self.curr_animation = Arc::clone(&animation);
+        }
+        self.animations.insert(name, animation);
+    }
+
+    /// Where the entity is in its current animation
+    pub fn cursor(&self) -> &AnimationCursor {
+        &self.cursor
+    }
+
+    /// If the entity has the animation specified
+    pub fn has_animation(&self, animation_name: AnimationName) -> bool {
+        self.animations.contains_key(animation_name)
+    }
+
+    /// Checks if the animation specified is not the current animation of the entity
+    pub fn is_new_animation(&self, animation_name: AnimationName) -> bool {
+        self.curr_animation.is_none() || self.curr_animation.get_name() != animation_name
+    }
+
+    /// If the entity is in a blocking animation
+    ///
+    /// usefull to determine for example whether or not to move an entity
+    pub fn in_blocking_animation(&self) -> bool {
+        self.in_blocking_animation
+    }
+
+    /// If the entity is in an animation
+    ///
+    /// useful for determining for example whether or not to initate another animation
+    pub fn in_animation(&self) -> bool {
+        self.curr_animation_called
+    }
+
+    /// If the entity is in the animation specified
+    ///
+    /// useful for determining for example whether or not to initate another animation
+    pub fn doing_animation(&self, animation_name: AnimationName) -> bool {
+        self.curr_animation_called && !self.is_new_animation(animation_name)
+    }
+
+    /// If the entity is playing an FX animation started by an [FXAnimationEvent]
+    pub fn is_fx_animation(&self) -> bool {
+        self.fx_animation
+    }
+
+    /// Freezes the current animation on its current frame
+    pub fn pause(&mut self) {
+        self.paused = true;
+    }
+
+    /// Continues the current animation after [AnimationPlayback::pause]
+    pub fn resume(&mut self) {
+        self.paused = false;
+    }
+
+    /// If the current animation is paused
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Stops the current animation and moves it back to its rest frame
+    pub fn stop(&mut self) {
+        self.pending_index = self
+            .curr_animation
+            .stop(&mut self.cursor, &self.last_valid_direction);
+        self.curr_animation_called = false;
+        self.in_blocking_animation = false;
+        self.paused = false;
+    }
+
+    /// Moves the current animation to the frame at `to`
+    pub fn seek(&mut self, to: AnimationSeek) {
+        let index = self
+            .curr_animation
+            .seek(&mut self.cursor, to, &self.last_valid_direction);
+        self.pending_index = index.or(self.pending_index);
+    }
+}
+
+/// Removes the [AnimationPlayback] along with the [Animator] so nothing is left animating on the entity
+pub(crate) fn remove_animation_playback(mut world: DeferredWorld, context: HookContext) {
+    world
+        .commands()
+        .entity(context.entity)
+        .try_remove::<AnimationPlayback>();
+}
+
+/// A change to the [AnimationPlayback] of entities made through [Animations] that is applied at the start of [AnimationSystems::Input]
+#[derive(Debug)]
+pub(crate) enum PlaybackChange {
+    /// Adds an animation to an entity
+    Attach {
+        entity: Entity,
+        name: AnimationName,
+        animation: Arc<AnimationType>,
+    },
+    /// Makes sure an entity has an [AnimationPlayback]
+    Insert { entity: Entity },
+    /// Pauses, resumes, stops or seeks the animation of an entity
+    Control {
+        entity: Entity,
+        control: PlaybackControl,
+    },
+    /// Swaps the animation for its reloaded version on every entity that has it
+    Reload {
+        animation: Arc<AnimationType>,
+        handles: Handles,
+        handles_changed: bool,
+        fx_animation: bool,
+    },
+}
+
+/// Applies the [PlaybackChange]s queued in [Animations] since the last time the animations were cycled
+pub(crate) fn apply_playback_changes(world: &mut World) {
+    let changes = world.resource_mut::<Animations>().take_playback_changes();
+    for change in changes {
+        match change {
+            PlaybackChange::Attach {
+                entity,
+                name,
+                animation,
+            } => {
+                let Ok(mut entity_mut) = world.get_entity_mut(entity) else {
+                    warn!(
+                        "Animation {} wasn't added to entity {:?} because it doesn't exist",
+                        name, entity
+                    );
+                    continue;
+                };
+                entity_mut
+                    .entry::<AnimationPlayback>()
+                    .or_default()
+                    .into_mut()
+                    .attach(name, animation);
+            }
+            PlaybackChange::Insert { entity } => {
+                if let Ok(mut entity) = world.get_entity_mut(entity) {
+                    entity.entry::<AnimationPlayback>().or_default();
+                }
+            }
+            PlaybackChange::Control { entity, control } => {
+                match world.get_mut::<AnimationPlayback>(entity) {
+                    Some(mut playback) => control.apply(&mut playback),
+                    None => warn!("Entity {:?} doesn't have an `AnimationPlayback`", entity),
+                }
+            }
+            PlaybackChange::Reload {
+                animation,
+                handles,
+                handles_changed,
+                fx_animation,
+            } => {
+                let name = animation.get_name();
+                let mut query = world.query::<(&mut AnimationPlayback, Option<&mut Sprite>)>();
+                for (mut playback, sprite) in query.iter_mut(world) {
+                    if playback.fx_animation != fx_animation {
+                        continue;
+                    }
+                    if !fx_animation {
+                        let Some(entity_animation) = playback.animations.get_mut(name) else {
+                            continue;
+                        };
+                        *entity_animation = Arc::clone(&animation);
+                    }
+                    if playback.curr_animation.is_none()
+                        || playback.curr_animation.get_name() != name
+                    {
+                        continue;
+                    }
+                    let playback = &mut *playback;
+                    animation.reload_cursor(&playback.curr_animation, &mut playback.cursor);
+                    playback.curr_animation = Arc::clone(&animation);
+                    if !handles_changed {
+                        continue;
+                    }
+                    let Some(mut sprite) = sprite else {
+                        continue;
+                    };
+                    // Point the entity at the new sprite sheet of the animation it's playing
+                    sprite.image = handles.image();
+                    if let Some(texture_atlas) = sprite.texture_atlas.as_mut() {
+                        texture_atlas.layout = handles.layout();
+                    }
+                }
+            }
+        }
+    }
+}
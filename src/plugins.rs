@@ -1,7 +1,11 @@
 use core::panic;
+use std::f32::consts::FRAC_PI_2;
 
-use bevy::ecs::schedule::IntoScheduleConfigs;
+use bevy::ecs::query::QueryEntityError;
+use bevy::ecs::schedule::{InternedScheduleLabel, IntoScheduleConfigs, ScheduleLabel};
+use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
+use bevy::sprite::Anchor;
 
 use crate::*;
 
@@ -14,97 +18,315 @@ use crate::*;
 /// App::new()
 ///     .add_plugins(DefaultPlugins)
 ///     .add_plugins(AnimationsPlugin {
-///         pixels_per_meter: 20. // your desired pixels_per_meter
+///         pixels_per_meter: 20., // your desired pixels_per_meter
+///         ..default()
 ///     })
 ///     .run()
 /// ```
 /// Note that the `pixels_per_meter` field will be used for your [`TransformAnimation`](crate::animations::TimedAnimation)
-#[derive(Debug, Default)]
+#[derive(Debug)]
 pub struct AnimationsPlugin {
     /// The number of pixels per meter
     pub pixels_per_meter: f32,
+    /// The schedule the [AnimationSystems] run in. Defaults to [Update]
+    pub schedule: InternedScheduleLabel,
+    /// The clock timed animations advance with unless they set their own. Defaults to [AnimationTimeSource::Virtual]
+    pub time_source: AnimationTimeSource,
+}
+
+impl Default for AnimationsPlugin {
+    fn default() -> Self {
+        Self {
+            pixels_per_meter: 0.,
+            schedule: Update.intern(),
+            time_source: AnimationTimeSource::default(),
+        }
+    }
+}
+
+impl AnimationsPlugin {
+    /// Runs the [AnimationSystems] in `schedule`, for example [FixedUpdate] or [PostUpdate]
+    pub fn with_schedule(mut self, schedule: impl ScheduleLabel) -> Self {
+        self.schedule = schedule.intern();
+        self
+    }
+
+    /// Advances timed animations with `time_source` unless they set their own
+    pub fn with_time_source(mut self, time_source: AnimationTimeSource) -> Self {
+        self.time_source = time_source;
+        self
+    }
+}
+
+/// The sets the systems of the [AnimationsPlugin] run in, one after the other in this order
+///
+/// # Example
+/// ```rust
+/// app.add_systems(Update, (
+///     move_player.before(AnimationSystems::Input),
+///     update_hitboxes.after(AnimationSystems::Apply),
+/// ));
+/// ```
+#[derive(SystemSet, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AnimationSystems {
+    /// Loads libraries, adds animations to entities, checks state machines and reads the pause, resume, stop and seek messages
+    Input,
+    /// Starts the requested animations and moves every playing animation to its next frame
+    Advance,
+    /// Applies what the new frames need on top of the sprite index, like frame pivots
+    Apply,
+    /// Despawns FX animations that finished
+    Cleanup,
 }
 
 impl Plugin for AnimationsPlugin {
     fn build(&self, app: &mut App) {
         app.insert_resource(AnimationsConfig {
             pixels_per_meter: self.pixels_per_meter,
+            time_source: self.time_source,
         })
         .add_message::<AnimationEvent>()
         .add_message::<ResetAnimationEvent>()
         .add_message::<FXAnimationEvent>()
+        .add_message::<AnimationFrameEvent>()
+        .add_message::<AnimationStarted>()
+        .add_message::<AnimationLooped>()
+        .add_message::<AnimationInterrupted>()
+        .add_message::<AnimationFinished>()
+        .add_message::<PauseAnimation>()
+        .add_message::<ResumeAnimation>()
+        .add_message::<StopAnimation>()
+        .add_message::<SeekAnimation>()
+        .init_asset::<AnimationLibrary>()
+        .init_asset::<AnimationStateGraph>()
+        .init_asset_loader::<AnimationLibraryLoader>()
+        .init_asset_loader::<AnimationStateGraphLoader>()
+        .init_asset_loader::<AsepriteLoader>()
+        .init_asset_loader::<GifLoader>()
+        .init_asset_loader::<TexturePackerLoader>()
+        .init_asset_loader::<TiledTilesetLoader>()
         .insert_resource(Animations::default())
-        .insert_resource(EntitesToRemove::default())
-        .add_systems(
-            Update,
+        .init_resource::<AnimationTimeScale>()
+        .init_resource::<FixedStepCount>()
+        .add_systems(FixedFirst, count_fixed_steps)
+        .configure_sets(
+            self.schedule,
             (
-                catch_fx_animation_events,
-                catch_animation_events,
-                catch_reset_events,
-                remove_entites,
+                AnimationSystems::Input,
+                AnimationSystems::Advance,
+                AnimationSystems::Apply,
+                AnimationSystems::Cleanup,
             )
                 .chain(),
+        )
+        .add_systems(
+            self.schedule,
+            (
+                (
+                    insert_animation_libraries,
+                    apply_playback_changes,
+                    catch_fx_animation_events,
+                    update_state_machines,
+                    catch_playback_events,
+                )
+                    .chain()
+                    .in_set(AnimationSystems::Input),
+                (catch_animation_events, catch_reset_events)
+                    .chain()
+                    .in_set(AnimationSystems::Advance),
+                apply_frame_pivots.in_set(AnimationSystems::Apply),
+                (despawn_finished_fx, mirror_animation_playback)
+                    .chain()
+                    .in_set(AnimationSystems::Cleanup),
+            ),
+        )
+        .add_systems(
+            PostUpdate,
+            (
+                rotate_frames.before(TransformSystems::Propagate),
+                restore_frame_rotations.after(TransformSystems::Propagate),
+            ),
         );
     }
 }
 
+/// The time that passed for the timed animations of an entity
+#[derive(SystemParam)]
+struct AnimationClock<'w, 's> {
+    virtual_time: Res<'w, Time<Virtual>>,
+    real_time: Res<'w, Time<Real>>,
+    fixed_time: Res<'w, Time<Fixed>>,
+    fixed_steps: Res<'w, FixedStepCount>,
+    time_scale: Res<'w, AnimationTimeScale>,
+    config: Res<'w, AnimationsConfig>,
+    readings: Local<'s, ClockReadings>,
+}
+
+/// What the [AnimationClock] read from each time source the last time it was updated
+#[derive(Default)]
+struct ClockReadings {
+    virtual_time: ElapsedReading,
+    real_time: ElapsedReading,
+    fixed_time: ElapsedReading,
+    counted_ticks: u64,
+    ticks: u32,
+}
+
+#[derive(Default)]
+struct ElapsedReading {
+    elapsed: Option<Duration>,
+    delta: Duration,
+}
+
+impl ElapsedReading {
+    /// Keeps how far the total `elapsed` time of a clock moved since the last reading
+    ///
+    /// Reading the total instead of the delta of the clock gives the right time in any schedule,
+    /// for example `Time<Virtual>` read from [FixedUpdate] only moves on the first step of a frame
+    fn read(&mut self, elapsed: Duration) {
+        self.delta = self
+            .elapsed
+            .map_or(Duration::ZERO, |last| elapsed.saturating_sub(last));
+        self.elapsed = Some(elapsed);
+    }
+}
+
+impl AnimationClock<'_, '_> {
+    /// Reads every time source, once each time the animations are cycled
+    fn update(&mut self) {
+        let readings = &mut *self.readings;
+        readings.virtual_time.read(self.virtual_time.elapsed());
+        readings.real_time.read(self.real_time.elapsed());
+        readings.fixed_time.read(self.fixed_time.elapsed());
+        let total_ticks = self.fixed_steps.0;
+        readings.ticks = total_ticks.saturating_sub(readings.counted_ticks) as u32;
+        readings.counted_ticks = total_ticks;
+    }
+
+    /// The time from `time_source`, or the one set on the plugin, scaled by the [AnimationTimeScale] and the [AnimationSpeed] of the entity
+    ///
+    /// Ticks aren't scaled so animations timed in ticks always play their frame data exactly
+    fn delta(
+        &self,
+        speed: Option<&AnimationSpeed>,
+        time_source: Option<AnimationTimeSource>,
+    ) -> AnimationDelta {
+        let speed = speed.map_or(1., |speed| speed.0);
+        let (delta, scale) = match time_source.unwrap_or(self.config.time_source) {
+            AnimationTimeSource::Virtual => (self.readings.virtual_time.delta, self.time_scale.0),
+            AnimationTimeSource::Real => (self.readings.real_time.delta, 1.),
+            AnimationTimeSource::Fixed => (self.readings.fixed_time.delta, self.time_scale.0),
+        };
+        AnimationDelta {
+            // A negative or NaN scale would panic so it freezes the animation instead
+            time: delta.mul_f32((scale * speed).max(0.)),
+            ticks: self.readings.ticks,
+        }
+    }
+}
+
+/// The messages read and sent while animating
+#[derive(SystemParam)]
+struct AnimationMessages<'w, 's> {
+    requests: MessageReader<'w, 's, AnimationEvent>,
+    frame: MessageWriter<'w, AnimationFrameEvent>,
+    lifecycle: AnimationLifecycle<'w>,
+}
+
+/// Sends the animation lifecycle messages and triggers them on the entity so observers on it and its parents see them
+#[derive(SystemParam)]
+struct AnimationLifecycle<'w> {
+    started: MessageWriter<'w, AnimationStarted>,
+    looped: MessageWriter<'w, AnimationLooped>,
+    interrupted: MessageWriter<'w, AnimationInterrupted>,
+    finished: MessageWriter<'w, AnimationFinished>,
+}
+
+impl AnimationLifecycle<'_> {
+    fn started(&mut self, commands: &mut Commands, event: AnimationStarted) {
+        commands.trigger(event.clone());
+        self.started.write(event);
+    }
+
+    fn looped(&mut self, commands: &mut Commands, event: AnimationLooped) {
+        commands.trigger(event.clone());
+        self.looped.write(event);
+    }
+
+    fn interrupted(&mut self, commands: &mut Commands, event: AnimationInterrupted) {
+        commands.trigger(event.clone());
+        self.interrupted.write(event);
+    }
+
+    fn finished(&mut self, commands: &mut Commands, event: AnimationFinished) {
+        commands.trigger(event.clone());
+        self.finished.write(event);
+    }
+}
+
+type AnimatingQuery<'a> = (
+    Entity,
+    &'a mut AnimationPlayback,
+    &'a mut Sprite,
+    &'a mut Transform,
+    &'a Animator,
+    Option<&'a AnimationSpeed>,
+);
+
 /// Main System That Checks for Incoming events
 /// If any incoming events are found they are checked to make sure they are new and if they are the Handle<TextureAtlas> is changed for the entity
-/// After checking the events. All the [`AnimatingEntities`](crate::AnimatingEntities) have their current animations cycled
+/// After checking the events. Every [AnimationPlayback] has its current animation cycled
 fn catch_animation_events(
-    time: Res<Time>,
-    mut query: Query<(&mut Sprite, &mut Transform, &Animator)>,
-    mut animations: ResMut<Animations>,
-    mut entities_to_remove: ResMut<EntitesToRemove>,
+    mut clock: AnimationClock,
+    mut query: Query<AnimatingQuery>,
+    animations: Res<Animations>,
     config: Res<AnimationsConfig>,
-    mut animation_events: MessageReader<AnimationEvent>,
+    mut messages: AnimationMessages,
     mut commands: Commands,
+    mut waiting_for_attach: Local<Vec<AnimationEvent>>,
 ) {
+    clock.update();
+    // Requests from last update whose animation was still being attached come first
+    let requests: Vec<AnimationEvent> = std::mem::take(&mut *waiting_for_attach)
+        .into_iter()
+        .chain(messages.requests.read().copied())
+        .collect();
     // Our main event loop
-    for event in animation_events.read() {
-        if !animations.has_entity(&event.1) {
-            panic!("Entity Not Found in Map For {} animation make sure your adding every necessary component to the entity i.e `Animator`", event.0);
-        }
+    for event in requests.iter() {
         if !animations.has_animation(event.0) {
             panic!("Animation {} not found", event.0);
         }
-        // Query the texture the sprite and the current direction of the entity
-        let (mut sprite, _, animator) = match query.get_mut(event.1) {
-            Ok(handle) => handle,
-            Err(_) => {
-                // If we didn't find the entity from the query it doesn't exist anymore and should be removed via the remove_entites system
-                entities_to_remove.0.push(event.1);
-                continue;
-            }
+        // Query the playback the sprite and the current direction of the entity
+        let (_, mut playback, mut sprite, _, animator, _) = match query.get_mut(event.1) {
+            Ok(query) => query,
+            // The entity was despawned after the animation was requested
+            Err(QueryEntityError::NotSpawned(_)) => continue,
+            Err(_) => panic!("Entity {:?} can't play {} animation make sure your adding every necessary component to the entity i.e `Animator`", event.1, event.0),
         };
+        let animating_entity = &mut *playback;
         let direction = animator.get_direction();
+        let mut replaced = false;
         // If incoming event is new
-        if animations
-            .is_new_animation(event.0, &event.1)
-            .unwrap_or_else(|| {
-                panic!(
-                    "Something Went Terribly Wrong Getting New Animation For {}",
-                    event.0
-                )
-            })
-        {
+        if animating_entity.is_new_animation(event.0) {
             let new_animation_handles = animations.get_handles(event.0).unwrap_or_else(|| {
                 panic!(
                     "Something Went Terribly Wrong Getting Animation For {}",
                     event.0
                 )
             });
-            // Get the animating entity from the entity passed in from our event
-            let animating_entity = animations.get_entity(&event.1).unwrap_or_else(|| panic!("Entity Not Found in Map For {} animation make sure your adding every necessary component to the entity i.e `AnimationDirection`", event.0));
-            // info!("{} {:?} {:?}", event.0, event.1, new_animation_handle.id());
 
-            // Get the Arc pointer for the animation
-            let Some(new_animation_arc) = animating_entity.animations.get(event.0) else {
-                panic!("Animation `{}` not found for {:?} make sure the name matches your configuration", event.0, animating_entity.entity);
+            // Get the shared definition of the animation
+            let Some(new_animation) = animating_entity.animations.get(event.0).cloned() else {
+                // Added to the entity this update after the attaches were applied so it can start next update
+                if animations.is_attaching(event.0, event.1) {
+                    waiting_for_attach.push(*event);
+                    continue;
+                }
+                panic!("Animation `{}` not found for {:?} make sure the name matches your configuration", event.0, event.1);
             };
 
-            // Unlock the animation if we don't do this we will hit a deadlock whenever we try to unlock the Arc<Mutex<>>
-            let mut new_animation = new_animation_arc.lock().unwrap();
+            // The entity starts the new animation from its first frame
+            let mut new_cursor = new_animation.cursor();
             let mut blocking = false;
             let mut new_priority = 0;
             let mut sprite_index = 0;
@@ -113,53 +335,69 @@ fn catch_animation_events(
             if let Some(new_timed_animation) = new_animation.timed_animation() {
                 blocking = new_timed_animation.blocking;
                 new_priority = new_timed_animation.blocking_priority;
-                sprite_index =
-                    new_timed_animation.sprite_index(&animating_entity.last_valid_direction);
+                sprite_index = new_timed_animation
+                    .sprite_index(&mut new_cursor, &animating_entity.last_valid_direction);
             } else if let Some(new_singe_frame_animation) = new_animation.single_frame_animation() {
                 blocking = new_singe_frame_animation.blocking;
                 new_priority = new_singe_frame_animation.blocking_priority;
-                sprite_index =
-                    new_singe_frame_animation.sprite_index(&animating_entity.last_valid_direction);
+                sprite_index = new_singe_frame_animation
+                    .sprite_index(&new_cursor, &animating_entity.last_valid_direction);
             }
             // If the new animation isn't a timed or single_frame one we don't care about blocking or priority
             else if let Some(new_transform_animation) = new_animation.transform_animation() {
-                sprite_index =
-                    new_transform_animation.sprite_index(&animating_entity.last_valid_direction);
+                sprite_index = new_transform_animation
+                    .sprite_index(&mut new_cursor, &animating_entity.last_valid_direction);
+            } else if let Some(new_linear_timed_animation) = new_animation.linear_timed_animation()
+            {
+                sprite_index = new_linear_timed_animation
+                    .sprite_index(&mut new_cursor, &animating_entity.last_valid_direction);
+            } else if let Some(new_linear_transform_animation) =
+                new_animation.linear_transform_animation()
+            {
+                sprite_index = new_linear_transform_animation
+                    .sprite_index(&mut new_cursor, &animating_entity.last_valid_direction);
             }
 
             // If we are in a blocking animation we don't want to changed our animation state
-            // info!("{}", animating_entity.in_blocking_animation);
             if animating_entity.in_blocking_animation {
                 // Check the new animations priority from the current one
-                let mut curr_animation = animating_entity.curr_animation.lock().unwrap();
+                let curr_animation = &animating_entity.curr_animation;
                 if let Some(curr_timed_animation) = curr_animation.timed_animation() {
                     if curr_timed_animation.blocking_priority > new_priority {
-                        // info!("blocking animation");
                         continue;
                     }
                 } else if let Some(curr_single_frame_animation) =
                     curr_animation.single_frame_animation()
                 {
-                    // info!("{} {} {}", curr_single_frame_animation.blocking_priority, new_priority, curr_single_frame_animation.blocking_finished);
                     if curr_single_frame_animation.blocking_priority > new_priority
-                        && !curr_single_frame_animation.blocking_finished
+                        && !animating_entity.cursor.blocking_finished()
                     {
-                        // info!("blocking animation");
                         continue;
                     }
                 } else {
-                    // info!("blocking animation");
                     continue;
                 }
             }
 
-            animating_entity
-                .curr_animation
-                .lock()
-                .unwrap()
-                .reset_animation();
-            animating_entity.curr_animation = new_animation_arc.clone();
+            let curr_animation = &animating_entity.curr_animation;
+            // The current animation is still playing so it gets cut off by the new one
+            if animating_entity.curr_animation_called && !curr_animation.is_none() {
+                messages.lifecycle.interrupted(
+                    &mut commands,
+                    AnimationInterrupted {
+                        entity: event.1,
+                        animation: curr_animation.get_name(),
+                        by: event.0,
+                    },
+                );
+            }
+            animating_entity.curr_animation = new_animation;
+            animating_entity.cursor = new_cursor;
+            // A paused or stopped animation doesn't carry over to the one replacing it
+            animating_entity.paused = false;
+            animating_entity.pending_index = None;
             animating_entity.in_blocking_animation = blocking;
+            replaced = true;
 
             sprite.texture_atlas = Some(TextureAtlas {
                 layout: new_animation_handles.layout(),
@@ -168,8 +406,20 @@ fn catch_animation_events(
             sprite.image = new_animation_handles.image();
         }
 
-        let animating_entity = animations.get_entity(&event.1).unwrap_or_else(|| panic!("Entity Not Found in Map For {} animation make sure your adding every necessary component to the entity i.e `AnimationDirection`", event.0));
-        animating_entity.curr_animation_called = true;
+        // An animation holding its last frame stays finished until another animation replaces it
+        let holding = animating_entity.cursor.is_holding();
+        if !holding {
+            if replaced || !animating_entity.curr_animation_called {
+                messages.lifecycle.started(
+                    &mut commands,
+                    AnimationStarted {
+                        entity: event.1,
+                        animation: event.0,
+                    },
+                );
+            }
+            animating_entity.curr_animation_called = true;
+        }
 
         // If our direction is changed we can set the current direction
         if animating_entity.curr_direction != *direction {
@@ -182,27 +432,26 @@ fn catch_animation_events(
     }
 
     // Our main animating loop
-    for (entity, animation_entity) in animations.entities.iter_mut() {
-        // Query the sprite and transform from the entity
-        let (sprite, transform, _) = match query.get_mut(*entity) {
-            Ok(query) => query,
-            Err(_) => {
-                // if we didn't find the entity in the query we should remove it as it doesn't exist anymore
-                if !animation_entity.fx_animation {
-                    entities_to_remove.0.push(*entity);
-                }
-                continue;
-            }
-        };
-        // unlock the current animation once so we don't hit a deadlock
-        let mut curr_animation = animation_entity.curr_animation.lock().unwrap();
+    for (entity, mut playback, mut sprite, transform, _, speed) in query.iter_mut() {
+        let animation_entity = &mut *playback;
+        // The definition is shared with every entity playing it, this entity only moves its own cursor
+        let curr_animation = &animation_entity.curr_animation;
+        let cursor = &mut animation_entity.cursor;
+
+        // Show the new frame of an animation that was stopped or seeked straight away
+        if let (Some(index), Some(atlas)) = (
+            animation_entity.pending_index.take(),
+            sprite.texture_atlas.as_mut(),
+        ) {
+            atlas.index = index;
+        }
 
-        // if the current animation wasn't started via an `AnimationEvent`
-        if !animation_entity.curr_animation_called {
+        // if the current animation wasn't started via an `AnimationEvent` or is paused
+        if !animation_entity.curr_animation_called || animation_entity.paused {
             continue;
         }
 
-        // info!("{}", curr_animation.get_name());
+        let name = curr_animation.get_name();
 
         // if the current animation is transform based we should cycle it
         if let Some(transform_animation) = curr_animation.transform_animation() {
@@ -211,6 +460,7 @@ fn catch_animation_events(
             }
             if transform_animation
                 .cycle_animation(
+                    cursor,
                     sprite,
                     &animation_entity.last_valid_direction,
                     transform,
@@ -218,37 +468,71 @@ fn catch_animation_events(
                 )
                 .is_none()
             {
-                if animation_entity.fx_animation {
-                    entities_to_remove.0.push(*entity);
-                    commands.entity(*entity).despawn();
-                }
+                messages.lifecycle.finished(
+                    &mut commands,
+                    AnimationFinished {
+                        entity,
+                        animation: name,
+                    },
+                );
                 animation_entity.curr_animation_called = false;
             }
         }
         // if our current animation is timed based we should cycle it
         else if let Some(timed_animation) = curr_animation.timed_animation() {
-            if timed_animation
-                .cycle_animation(sprite, &animation_entity.last_valid_direction, time.delta())
-                .is_none()
-            {
-                if animation_entity.fx_animation {
-                    entities_to_remove.0.push(*entity);
-                    commands.entity(*entity).despawn();
+            let cycled = timed_animation.cycle_animation(
+                cursor,
+                sprite,
+                &animation_entity.last_valid_direction,
+                clock.delta(speed, timed_animation.time_source),
+            );
+            for frame in cursor.take_shown_frames() {
+                for marker in timed_animation.markers(frame) {
+                    messages.frame.write(AnimationFrameEvent {
+                        entity,
+                        animation: name,
+                        marker,
+                        frame,
+                    });
                 }
+            }
+            if cycled.is_none() {
+                messages.lifecycle.finished(
+                    &mut commands,
+                    AnimationFinished {
+                        entity,
+                        animation: name,
+                    },
+                );
                 animation_entity.in_blocking_animation = false;
                 animation_entity.curr_animation_called = false;
             }
         }
         // if the current animation is linear time based we should cycle it
         else if let Some(linear_timed_animation) = curr_animation.linear_timed_animation() {
-            if linear_timed_animation
-                .cycle_animation(sprite, time.delta())
-                .is_none()
-            {
-                if animation_entity.fx_animation {
-                    entities_to_remove.0.push(*entity);
-                    commands.entity(*entity).despawn();
+            let cycled = linear_timed_animation.cycle_animation(
+                cursor,
+                sprite,
+                clock.delta(speed, linear_timed_animation.time_source),
+            );
+            for frame in cursor.take_shown_frames() {
+                for marker in linear_timed_animation.markers(frame) {
+                    messages.frame.write(AnimationFrameEvent {
+                        entity,
+                        animation: name,
+                        marker,
+                        frame,
+                    });
                 }
+            }
+            if cycled.is_none() {
+                messages.lifecycle.finished(
+                    &mut commands,
+                    AnimationFinished {
+                        entity,
+                        animation: name,
+                    },
+                );
                 animation_entity.in_blocking_animation = false;
                 animation_entity.curr_animation_called = false;
             }
@@ -257,22 +541,26 @@ fn catch_animation_events(
         else if let Some(linear_transform_animation) = curr_animation.linear_transform_animation()
         {
             if linear_transform_animation
-                .cycle_animation(sprite, transform, config.pixels_per_meter)
+                .cycle_animation(cursor, sprite, transform, config.pixels_per_meter)
                 .is_none()
             {
-                if animation_entity.fx_animation {
-                    entities_to_remove.0.push(*entity);
-                    commands.entity(*entity).despawn();
-                }
+                messages.lifecycle.finished(
+                    &mut commands,
+                    AnimationFinished {
+                        entity,
+                        animation: name,
+                    },
+                );
                 animation_entity.curr_animation_called = false;
             }
         }
         // if the current animation is a single frame animation
         else if let Some(single_frame_animation) = curr_animation.single_frame_animation() {
             single_frame_animation.cycle_animation(
+                cursor,
                 sprite,
                 &animation_entity.last_valid_direction,
-                time.delta(),
+                clock.delta(speed, single_frame_animation.time_source).time,
             )
         }
         // if we get here something bad happened it will most likely never hit as the typing is pretty strong
@@ -282,53 +570,124 @@ fn catch_animation_events(
                 curr_animation.get_name()
             );
         }
+
+        // a repeating animation started over this frame
+        if cursor.take_looped() {
+            messages.lifecycle.looped(
+                &mut commands,
+                AnimationLooped {
+                    entity,
+                    animation: name,
+                },
+            );
+        }
+    }
+}
+
+/// Takes the transitions of every [AnimationStateMachine] that can be taken and starts the animation of its current state
+fn update_state_machines(
+    mut query: Query<(Entity, &mut AnimationStateMachine, &AnimationPlayback)>,
+    graphs: Res<Assets<AnimationStateGraph>>,
+    animations: Res<Animations>,
+    mut animation_events: MessageWriter<AnimationEvent>,
+) {
+    for (entity, mut state_machine, playback) in query.iter_mut() {
+        // The graph is an asset that hasn't loaded yet
+        let Some(graph) = state_machine.graph_ref(&graphs) else {
+            continue;
+        };
+        state_machine.update_state(&graph);
+        // The initial state isn't in the graph
+        let Some(curr_animation) = state_machine.current_animation() else {
+            continue;
+        };
+        state_machine.update_playing(playback.doing_animation(curr_animation));
+        state_machine.take_transition(&graph);
+        if !state_machine.needs_start() {
+            continue;
+        }
+        let Some(curr_animation) = state_machine.current_animation() else {
+            continue;
+        };
+        if playback.has_animation(curr_animation) || animations.is_attaching(curr_animation, entity)
+        {
+            animation_events.write(AnimationEvent(curr_animation, entity));
+        } else {
+            warn!(
+                "The state machine of entity {:?} is in a state that plays {} but the animation wasn't added to the entity",
+                entity, curr_animation
+            );
+        }
+    }
+}
+
+/// Pauses, resumes, stops and seeks the [AnimationPlayback] of the entities the messages were sent for
+fn catch_playback_events(
+    mut query: Query<&mut AnimationPlayback>,
+    mut pause_events: MessageReader<PauseAnimation>,
+    mut resume_events: MessageReader<ResumeAnimation>,
+    mut stop_events: MessageReader<StopAnimation>,
+    mut seek_events: MessageReader<SeekAnimation>,
+) {
+    for event in pause_events.read() {
+        change_playback(&mut query, event.0, AnimationPlayback::pause);
+    }
+    for event in resume_events.read() {
+        change_playback(&mut query, event.0, AnimationPlayback::resume);
+    }
+    for event in stop_events.read() {
+        change_playback(&mut query, event.0, AnimationPlayback::stop);
+    }
+    for event in seek_events.read() {
+        change_playback(&mut query, event.entity, |playback| playback.seek(event.to));
+    }
+}
+
+fn change_playback(
+    query: &mut Query<&mut AnimationPlayback>,
+    entity: Entity,
+    change: impl FnOnce(&mut AnimationPlayback),
+) {
+    match query.get_mut(entity) {
+        Ok(mut playback) => change(&mut playback),
+        Err(_) => warn!("Entity {:?} doesn't have an `AnimationPlayback`", entity),
     }
 }
 
 fn catch_reset_events(
-    mut query: Query<(&mut Sprite, &Animator)>,
-    mut animations: ResMut<Animations>,
-    mut entities_to_remove: ResMut<EntitesToRemove>,
+    mut query: Query<(&mut Sprite, &Animator, &mut AnimationPlayback)>,
     mut animation_events: MessageReader<ResetAnimationEvent>,
 ) {
     for event in animation_events.read() {
-        // If the entity wasn't found in the query we want to remove it from our data structure
-        let (sprite, animator) = match query.get_mut(event.0) {
-            Ok(q) => q,
-            Err(_) => {
-                entities_to_remove.0.push(event.0);
-                continue;
-            }
+        // The entity was despawned after the reset was requested
+        let Ok((sprite, animator, mut playback)) = query.get_mut(event.0) else {
+            continue;
         };
         let direction = animator.get_direction();
-        let mut curr_animation = animations
-            .entities
-            .get_mut(&event.0)
-            .expect("Entity Not Found from `ResetAnimationEvent`")
-            .curr_animation
-            .lock()
-            .unwrap();
+        let playback = &mut *playback;
+        let curr_animation = &playback.curr_animation;
+        let cursor = &mut playback.cursor;
         // Try and get the current animation
         // If it is time based
         if let Some(timed_animation) = curr_animation.timed_animation() {
-            timed_animation.reset_animation(Some(sprite), Some(direction));
+            timed_animation.reset_animation(cursor, Some(sprite), Some(direction));
         }
         // If it is transform based
         else if let Some(transform_animation) = curr_animation.transform_animation() {
-            transform_animation.reset_animation(Some(sprite), Some(direction));
+            transform_animation.reset_animation(cursor, Some(sprite), Some(direction));
         }
         // If it is linear time based
         else if let Some(linear_timed_animation) = curr_animation.linear_timed_animation() {
-            linear_timed_animation.reset_animation(Some(sprite));
+            linear_timed_animation.reset_animation(cursor, Some(sprite));
         }
         // If it is linear transform based
         else if let Some(linear_transform_animation) = curr_animation.linear_transform_animation()
         {
-            linear_transform_animation.reset_animation(Some(sprite));
+            linear_transform_animation.reset_animation(cursor, Some(sprite));
         }
         // If it is single frame based
         else if let Some(single_frame_animation) = curr_animation.single_frame_animation() {
-            single_frame_animation.reset_animation(Some(sprite), Some(direction));
+            single_frame_animation.reset_animation(cursor, Some(sprite), Some(direction));
         } else {
             panic!("Something went terribly wrong resetting the current animation");
         }
@@ -338,31 +697,835 @@ fn catch_reset_events(
 fn catch_fx_animation_events(
     mut event_reader: MessageReader<FXAnimationEvent>,
     mut commands: Commands,
-    mut animations: ResMut<Animations>,
+    animations: Res<Animations>,
+    mut lifecycle: AnimationLifecycle,
 ) {
     for event in event_reader.read() {
-        let entity = commands.spawn(Animator::default()).id();
-        let Some(sprite_sheet_bundle) = animations.start_fx_animation(entity, event.0, event.1)
+        let Some((sprite_sheet_bundle, playback)) = animations.start_fx_animation(event.0, event.1)
         else {
             warn!("There was a problem spawning your FXAnimation {}", event.0);
             continue;
         };
 
-        commands
-            .entity(entity)
-            .insert(sprite_sheet_bundle)
-            .insert(FXAnimation)
-            .insert(Name::new("FX Animation"));
+        let entity = commands
+            .spawn((
+                Animator::default(),
+                playback,
+                sprite_sheet_bundle,
+                FXAnimation,
+                Name::new("FX Animation"),
+            ))
+            .id();
+        lifecycle.started(
+            &mut commands,
+            AnimationStarted {
+                entity,
+                animation: event.0,
+            },
+        );
     }
 }
 
-/// This system is for any cleanup of despawned entities
-fn remove_entites(
+/// Inserts the animations of every [AnimationLibrary] that finished loading into the animation pool
+///
+/// When a library file changes on disk its animations are reloaded on every entity using them and the ones
+/// removed from the file are removed from the pool
+fn insert_animation_libraries(
+    mut asset_events: MessageReader<AssetEvent<AnimationLibrary>>,
+    libraries: Res<Assets<AnimationLibrary>>,
     mut animations: ResMut<Animations>,
-    mut entities_to_remove: ResMut<EntitesToRemove>,
 ) {
-    for entity in entities_to_remove.0.iter() {
-        animations.entities.remove(entity);
+    for event in asset_events.read() {
+        // Reloading a library sends a `Modified` as well, which would insert it twice
+        let AssetEvent::LoadedWithDependencies { id } = event else {
+            continue;
+        };
+        let Some(library) = libraries.get(*id) else {
+            continue;
+        };
+        animations.insert_library(*id, library);
+    }
+}
+
+type FramePivotQuery<'a> = (
+    Entity,
+    &'a Sprite,
+    &'a mut Anchor,
+    Option<&'a mut PivotedFrame>,
+);
+
+/// Sets the [Anchor] of every sprite whose frame changed to the [FramePivot] of that frame
+///
+/// Sprites showing a frame without a pivot get their own anchor back
+fn apply_frame_pivots(
+    mut query: Query<FramePivotQuery, Changed<Sprite>>,
+    animations: Res<Animations>,
+    mut commands: Commands,
+) {
+    for (entity, sprite, mut anchor, pivoted) in query.iter_mut() {
+        let Some(texture_atlas) = sprite.texture_atlas.as_ref() else {
+            continue;
+        };
+        let pivot = animations.get_frame_pivot(texture_atlas.layout.id(), texture_atlas.index);
+        let new_anchor = match (pivot, pivoted) {
+            (Some(pivot), Some(mut pivoted)) => {
+                pivoted.rotated = pivot.rotated;
+                pivoted.restored = false;
+                pivot.anchor
+            }
+            (Some(pivot), None) => {
+                commands.entity(entity).insert(PivotedFrame {
+                    original_anchor: anchor.0,
+                    rotated: pivot.rotated,
+                    original_rotation: None,
+                    restored: false,
+                });
+                pivot.anchor
+            }
+            (None, Some(mut pivoted)) => {
+                // `rotate_frames` removes it once the sprite isn't rotated anymore
+                pivoted.rotated = false;
+                pivoted.restored = true;
+                pivoted.original_anchor
+            }
+            (None, None) => continue,
+        };
+        if anchor.0 != new_anchor {
+            anchor.0 = new_anchor;
+        }
+    }
+}
+
+/// Rotates sprites showing a frame that is rotated in its sprite sheet back upright
+///
+/// The rotation is added to the [Transform] just before it is propagated, so the [GlobalTransform] of the entity and its
+/// children include it, and [restore_frame_rotations] takes it out again right after.
+/// Flipped sprites are rotated the other way so they are mirrored like any other frame
+fn rotate_frames(mut query: Query<(&mut PivotedFrame, &Sprite, &mut Transform)>) {
+    for (mut pivoted, sprite, mut transform) in query.iter_mut() {
+        let rotation = match (pivoted.rotated, sprite.flip_x == sprite.flip_y) {
+            (false, _) => {
+                // The frame was rotated last time, so the transform has to be propagated again without it
+                if pivoted.original_rotation.take().is_some() {
+                    transform.set_changed();
+                }
+                continue;
+            }
+            (true, true) => FRAC_PI_2,
+            (true, false) => -FRAC_PI_2,
+        };
+        pivoted.original_rotation = Some(transform.rotation);
+        transform.rotate_local_z(rotation);
+    }
+}
+
+/// Gives sprites rotated by [rotate_frames] their own rotation back once transforms are propagated
+///
+/// Sprites showing a frame without a pivot again lose their [PivotedFrame]
+fn restore_frame_rotations(
+    mut query: Query<(Entity, &PivotedFrame, &mut Transform)>,
+    mut commands: Commands,
+) {
+    for (entity, pivoted, mut transform) in query.iter_mut() {
+        if let Some(rotation) = pivoted.original_rotation {
+            // The rotation is added again before the next propagation, so there's no need to propagate this change
+            transform.bypass_change_detection().rotation = rotation;
+        }
+        if pivoted.restored {
+            commands.entity(entity).remove::<PivotedFrame>();
+        }
+    }
+}
+
+/// Despawns every FX animation that isn't playing anymore
+fn despawn_finished_fx(
+    query: Query<(Entity, &AnimationPlayback), With<FXAnimation>>,
+    mut commands: Commands,
+) {
+    for (entity, playback) in query.iter() {
+        if !playback.in_animation() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::message::MessageCursor;
+    use bevy::time::TimeUpdateStrategy;
+    use bevy::transform::TransformPlugin;
+
+    use super::*;
+
+    /// An app with the `plugin` where every update moves time forward by `step`
+    fn animations_app(plugin: AnimationsPlugin, step: Duration) -> App {
+        let mut app = App::new();
+        app.add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            ImagePlugin::default(),
+        ))
+        .init_asset::<TextureAtlasLayout>()
+        .add_plugins(plugin)
+        .insert_resource(TimeUpdateStrategy::ManualDuration(step));
+        app
+    }
+
+    /// Spawns an entity that can play animations
+    fn spawn_animated(app: &mut App) -> Entity {
+        app.world_mut()
+            .spawn((
+                Animator::default(),
+                Sprite {
+                    texture_atlas: Some(TextureAtlas::default()),
+                    ..Default::default()
+                },
+                Transform::default(),
+            ))
+            .id()
+    }
+
+    fn shown_index(app: &App, entity: Entity) -> usize {
+        let sprite = app.world().get::<Sprite>(entity).unwrap();
+        sprite.texture_atlas.as_ref().unwrap().index
+    }
+
+    fn linear_timed(name: AnimationName, frames: Vec<usize>, loop_mode: LoopMode) -> NewAnimation {
+        let timings = vec![0.1; frames.len()];
+        NewAnimation {
+            handles: Handles::default(),
+            animation: AnimationType::LinearTimed(
+                LinearTimedAnimation::new(frames, timings, loop_mode),
+                name,
+            ),
+        }
+    }
+
+    /// The messages of type `M` sent since `cursor` last read them
+    fn read_messages<M: Message + Clone>(app: &App, cursor: &mut MessageCursor<M>) -> Vec<M> {
+        cursor
+            .read(app.world().resource::<Messages<M>>())
+            .cloned()
+            .collect()
+    }
+
+    #[test]
+    fn animations_attached_and_started_in_the_same_update_play() {
+        let mut app = animations_app(AnimationsPlugin::default(), Duration::from_millis(100));
+        let entity = spawn_animated(&mut app);
+        app.world_mut()
+            .resource_mut::<Animations>()
+            .insert_animation(linear_timed("spin", vec![3, 4, 5], LoopMode::Loop), None);
+        // Runs after the attaches were applied but before the animations start
+        let attach_and_start = move |mut animations: ResMut<Animations>,
+                                     mut requests: MessageWriter<AnimationEvent>,
+                                     mut done: Local<bool>| {
+            if !std::mem::replace(&mut *done, true) {
+                animations.add_animation_to_entity("spin", entity).unwrap();
+                requests.write(AnimationEvent("spin", entity));
+            }
+        };
+        app.add_systems(
+            Update,
+            attach_and_start
+                .after(AnimationSystems::Input)
+                .before(AnimationSystems::Advance),
+        );
+
+        app.update();
+        app.update();
+        let playback = app.world().get::<AnimationPlayback>(entity).unwrap();
+        assert!(playback.doing_animation("spin"));
+        assert!((3..=5).contains(&shown_index(&app, entity)));
+    }
+
+    #[test]
+    fn state_machines_skip_animations_missing_from_the_entity() {
+        let mut app = animations_app(AnimationsPlugin::default(), Duration::from_millis(100));
+        let entity = spawn_animated(&mut app);
+        app.world_mut()
+            .resource_mut::<Animations>()
+            .insert_animation(
+                linear_timed("idle", vec![0, 1], LoopMode::Loop),
+                Some(entity),
+            );
+        let graph = AnimationStateGraph::new("idle")
+            .with_state("idle", "idle")
+            .with_state("run", "run")
+            .with_transition(
+                AnimationTransition::new("idle", "run").when(AnimationCondition::Bool("run", true)),
+            );
+        app.world_mut()
+            .entity_mut(entity)
+            .insert(AnimationStateMachine::new(graph));
+
+        app.update();
+        app.update();
+        let playback = app.world().get::<AnimationPlayback>(entity).unwrap();
+        assert!(playback.doing_animation("idle"));
+
+        // "run" was never added to the entity, so the state is entered without starting it
+        app.world_mut()
+            .get_mut::<AnimationStateMachine>(entity)
+            .unwrap()
+            .set_bool("run", true);
+        app.update();
+        app.update();
+        let state_machine = app.world().get::<AnimationStateMachine>(entity).unwrap();
+        assert_eq!(state_machine.current_state(), Some("run"));
+        let playback = app.world().get::<AnimationPlayback>(entity).unwrap();
+        assert!(!playback.doing_animation("run"));
+    }
+
+    #[test]
+    fn long_updates_show_every_frame_they_cross() {
+        let mut app = animations_app(AnimationsPlugin::default(), Duration::from_millis(250));
+        let entity = spawn_animated(&mut app);
+        let animation = LinearTimedAnimation::new(vec![3, 4, 5, 6], vec![0.1; 4], LoopMode::Once)
+            .with_marker(0, "start")
+            .with_marker(1, "step")
+            .with_marker(2, "mid");
+        app.world_mut()
+            .resource_mut::<Animations>()
+            .insert_animation(
+                NewAnimation {
+                    handles: Handles::default(),
+                    animation: AnimationType::LinearTimed(animation, "count"),
+                },
+                Some(entity),
+            );
+        let mut reader = app
+            .world()
+            .resource::<Messages<AnimationFrameEvent>>()
+            .get_cursor();
+        let mut markers = |app: &App| -> Vec<_> {
+            reader
+                .read(app.world().resource::<Messages<AnimationFrameEvent>>())
+                .map(|event| (event.frame, event.marker))
+                .collect()
+        };
+
+        // The first frame and its markers show as soon as the animation starts
+        app.world_mut()
+            .write_message(AnimationEvent("count", entity));
+        app.update();
+        assert_eq!(shown_index(&app, entity), 3);
+        assert_eq!(markers(&app), [(0, "start")]);
+
+        // A quarter second is the rest of the first frame, all of the second and the start of the third
+        app.update();
+        assert_eq!(shown_index(&app, entity), 5);
+        assert_eq!(markers(&app), [(1, "step"), (2, "mid")]);
+
+        app.update();
+        assert_eq!(shown_index(&app, entity), 6);
+        assert!(markers(&app).is_empty());
+        app.update();
+        let playback = app.world().get::<AnimationPlayback>(entity).unwrap();
+        assert!(!playback.doing_animation("count"));
+    }
+
+    #[test]
+    fn tick_timings_count_fixed_steps() {
+        let mut app = animations_app(AnimationsPlugin::default(), Duration::from_millis(100));
+        app.insert_resource(Time::<Fixed>::from_hz(10.));
+        let entity = spawn_animated(&mut app);
+        let animation = LinearTimedAnimation::new(vec![3, 4, 5, 6], Vec::new(), LoopMode::Once)
+            .with_tick_timings(vec![2, 1, 3, 1]);
+        app.world_mut()
+            .resource_mut::<Animations>()
+            .insert_animation(
+                NewAnimation {
+                    handles: Handles::default(),
+                    animation: AnimationType::LinearTimed(animation, "ticks"),
+                },
+                Some(entity),
+            );
+        app.update();
+        app.world_mut()
+            .write_message(AnimationEvent("ticks", entity));
+        let mut shown = Vec::new();
+        for update in 0..6 {
+            // Halving the timestep makes every update two steps without skipping any ticks
+            if update == 3 {
+                app.world_mut()
+                    .resource_mut::<Time<Fixed>>()
+                    .set_timestep(Duration::from_millis(50));
+            }
+            app.update();
+            shown.push(shown_index(&app, entity));
+        }
+        // Each frame is shown for its ticks: 2, then 1, then 3 over the one and two step updates
+        assert_eq!(shown, [3, 3, 4, 5, 6, 6]);
+        let playback = app.world().get::<AnimationPlayback>(entity).unwrap();
+        assert!(!playback.doing_animation("ticks"));
+    }
+
+    fn angle(app: &App, entity: Entity) -> f32 {
+        let global_transform = app.world().get::<GlobalTransform>(entity).unwrap();
+        let (z, _, _) = global_transform
+            .compute_transform()
+            .rotation
+            .to_euler(EulerRot::ZYX);
+        z
+    }
+
+    #[test]
+    fn frame_pivots_leave_the_transform_alone() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, TransformPlugin))
+            .init_resource::<Animations>()
+            .add_systems(Update, apply_frame_pivots)
+            .add_systems(
+                PostUpdate,
+                (
+                    rotate_frames.before(TransformSystems::Propagate),
+                    restore_frame_rotations.after(TransformSystems::Propagate),
+                ),
+            );
+        let layout = Handle::<TextureAtlasLayout>::default();
+        app.world_mut()
+            .resource_mut::<Animations>()
+            .insert_frame_pivots(
+                &layout,
+                vec![
+                    FramePivot {
+                        anchor: Vec2::new(0.25, 0.),
+                        rotated: true,
+                    },
+                    FramePivot {
+                        anchor: Vec2::new(0.3, 0.),
+                        rotated: false,
+                    },
+                ],
+            );
+        let user_rotation = Quat::from_rotation_z(0.3);
+        let entity = app
+            .world_mut()
+            .spawn((
+                Sprite {
+                    texture_atlas: Some(TextureAtlas { layout, index: 0 }),
+                    ..Default::default()
+                },
+                Anchor(Vec2::new(0.1, 0.1)),
+                Transform::from_rotation(user_rotation),
+            ))
+            .id();
+        let child = app
+            .world_mut()
+            .spawn((Transform::default(), ChildOf(entity)))
+            .id();
+        let set_frame = |app: &mut App, index: usize| {
+            let mut sprite = app.world_mut().get_mut::<Sprite>(entity).unwrap();
+            sprite.texture_atlas.as_mut().unwrap().index = index;
+        };
+
+        app.update();
+        assert_eq!(
+            app.world().get::<Anchor>(entity).unwrap().0,
+            Vec2::new(0.25, 0.)
+        );
+        assert!((angle(&app, entity) - (0.3 + FRAC_PI_2)).abs() < 1e-4);
+        // Children are rotated along with the frame
+        assert!((angle(&app, child) - (0.3 + FRAC_PI_2)).abs() < 1e-4);
+        // Nothing changed so the rotation isn't added again
+        app.update();
+        assert!((angle(&app, entity) - (0.3 + FRAC_PI_2)).abs() < 1e-4);
+        assert_eq!(
+            app.world().get::<Transform>(entity).unwrap().rotation,
+            user_rotation
+        );
+
+        app.world_mut().get_mut::<Sprite>(entity).unwrap().flip_x = true;
+        app.update();
+        assert!((angle(&app, entity) - (0.3 - FRAC_PI_2)).abs() < 1e-4);
+        // Moving the entity keeps the frame rotated
+        app.world_mut()
+            .get_mut::<Transform>(entity)
+            .unwrap()
+            .translation
+            .x = 5.;
+        app.update();
+        assert!((angle(&app, entity) - (0.3 - FRAC_PI_2)).abs() < 1e-4);
+        let global_transform = app.world().get::<GlobalTransform>(entity).unwrap();
+        assert_eq!(global_transform.translation().x, 5.);
+
+        set_frame(&mut app, 1);
+        app.update();
+        assert_eq!(
+            app.world().get::<Anchor>(entity).unwrap().0,
+            Vec2::new(0.3, 0.)
+        );
+        assert!((angle(&app, entity) - 0.3).abs() < 1e-4);
+        assert!((angle(&app, child) - 0.3).abs() < 1e-4);
+
+        // A frame without a pivot gets the sprite's own anchor back
+        set_frame(&mut app, 2);
+        app.update();
+        assert_eq!(
+            app.world().get::<Anchor>(entity).unwrap().0,
+            Vec2::new(0.1, 0.1)
+        );
+        assert!((angle(&app, entity) - 0.3).abs() < 1e-4);
+        assert!(app.world().get::<PivotedFrame>(entity).is_none());
+        assert_eq!(
+            app.world().get::<Transform>(entity).unwrap().rotation,
+            user_rotation
+        );
+    }
+
+    #[test]
+    fn markers_are_sent_every_time_their_frame_shows() {
+        let mut app = animations_app(AnimationsPlugin::default(), Duration::from_millis(130));
+        let entity = spawn_animated(&mut app);
+        let animation = TimedAnimation::new(
+            vec![4, 5, 6],
+            vec![0.1; 3],
+            Vec2::new(8., 1.),
+            AnimationDirectionIndexes::FX(FXBasedDirection { index: 0 }),
+            LoopMode::Loop,
+            false,
+            0,
+        )
+        .with_marker(0, "lift")
+        .with_marker(1, "step");
+        app.world_mut()
+            .resource_mut::<Animations>()
+            .insert_animation(
+                NewAnimation {
+                    handles: Handles::default(),
+                    animation: AnimationType::Timed(animation, "walk"),
+                },
+                Some(entity),
+            );
+        let mut cursor = app
+            .world()
+            .resource::<Messages<AnimationFrameEvent>>()
+            .get_cursor();
+        let mut markers = |app: &App| -> Vec<_> {
+            read_messages(app, &mut cursor)
+                .into_iter()
+                .map(|event| {
+                    assert_eq!(event.entity, entity);
+                    assert_eq!(event.animation, "walk");
+                    (event.frame, event.marker)
+                })
+                .collect()
+        };
+
+        app.world_mut()
+            .write_message(AnimationEvent("walk", entity));
+        let mut sent = Vec::new();
+        for _ in 0..5 {
+            app.update();
+            sent.push(markers(&app));
+        }
+        // The updates show the frames 0, 1, 2, then 0 and 1 again after looping
+        assert_eq!(
+            sent,
+            [
+                vec![(0, "lift")],
+                vec![(1, "step")],
+                vec![],
+                vec![(0, "lift")],
+                vec![(1, "step")],
+            ]
+        );
+    }
+
+    #[test]
+    fn lifecycle_messages_follow_the_animations() {
+        let mut app = animations_app(AnimationsPlugin::default(), Duration::from_millis(130));
+        let entity = spawn_animated(&mut app);
+        let mut animations = app.world_mut().resource_mut::<Animations>();
+        animations.insert_animation(
+            linear_timed("walk", vec![0, 1], LoopMode::Loop),
+            Some(entity),
+        );
+        animations.insert_animation(
+            linear_timed("attack", vec![2, 3], LoopMode::Once),
+            Some(entity),
+        );
+        let world = app.world();
+        let mut started = world.resource::<Messages<AnimationStarted>>().get_cursor();
+        let mut looped = world.resource::<Messages<AnimationLooped>>().get_cursor();
+        let mut interrupted = world
+            .resource::<Messages<AnimationInterrupted>>()
+            .get_cursor();
+        let mut finished = world.resource::<Messages<AnimationFinished>>().get_cursor();
+        let mut update = |app: &mut App| -> Vec<String> {
+            app.update();
+            let mut sent = Vec::new();
+            for event in read_messages(app, &mut interrupted) {
+                sent.push(format!("{} interrupted by {}", event.animation, event.by));
+            }
+            for event in read_messages(app, &mut started) {
+                sent.push(format!("{} started", event.animation));
+            }
+            for event in read_messages(app, &mut looped) {
+                sent.push(format!("{} looped", event.animation));
+            }
+            for event in read_messages(app, &mut finished) {
+                sent.push(format!("{} finished", event.animation));
+            }
+            sent
+        };
+
+        app.world_mut()
+            .write_message(AnimationEvent("walk", entity));
+        assert_eq!(update(&mut app), ["walk started"]);
+        assert!(update(&mut app).is_empty());
+        assert_eq!(update(&mut app), ["walk looped"]);
+
+        app.world_mut()
+            .write_message(AnimationEvent("attack", entity));
+        assert_eq!(
+            update(&mut app),
+            ["walk interrupted by attack", "attack started"]
+        );
+        assert!(update(&mut app).is_empty());
+        assert_eq!(update(&mut app), ["attack finished"]);
+        assert!(update(&mut app).is_empty());
+    }
+
+    /// The lifecycle events observers saw as `(observed entity, animating entity, event)`
+    #[derive(Resource, Default)]
+    struct Observed(Vec<(Entity, Entity, String)>);
+
+    #[test]
+    fn lifecycle_events_reach_observers_on_parents() {
+        let mut app = animations_app(AnimationsPlugin::default(), Duration::from_millis(130));
+        app.init_resource::<Observed>();
+        let parent = app
+            .world_mut()
+            .spawn_empty()
+            .observe(
+                |event: On<AnimationStarted>, mut observed: ResMut<Observed>| {
+                    observed.0.push((
+                        event.entity,
+                        event.original_event_target(),
+                        format!("{} started", event.animation),
+                    ));
+                },
+            )
+            .observe(
+                |event: On<AnimationFinished>, mut observed: ResMut<Observed>| {
+                    observed.0.push((
+                        event.entity,
+                        event.original_event_target(),
+                        format!("{} finished", event.animation),
+                    ));
+                },
+            )
+            .id();
+        let entity = spawn_animated(&mut app);
+        app.world_mut()
+            .entity_mut(entity)
+            .insert(ChildOf(parent))
+            .observe(
+                |event: On<AnimationFinished>, mut observed: ResMut<Observed>| {
+                    observed.0.push((
+                        event.entity,
+                        event.original_event_target(),
+                        format!("{} finished", event.animation),
+                    ));
+                },
+            );
+        app.world_mut()
+            .resource_mut::<Animations>()
+            .insert_animation(
+                linear_timed("attack", vec![2, 3], LoopMode::Once),
+                Some(entity),
+            );
+
+        app.world_mut()
+            .write_message(AnimationEvent("attack", entity));
+        for _ in 0..4 {
+            app.update();
+        }
+        // The entity's own observer sees the event before it propagates to the parent
+        assert_eq!(
+            app.world().resource::<Observed>().0,
+            [
+                (parent, entity, "attack started".to_string()),
+                (entity, entity, "attack finished".to_string()),
+                (parent, entity, "attack finished".to_string()),
+            ]
+        );
+    }
+
+    /// The frames an entity with `speed` shows over 4 updates of 60ms with the time scaled by `time_scale`
+    fn shown_with_speed(speed: f32, time_scale: f32) -> Vec<usize> {
+        let mut app = animations_app(AnimationsPlugin::default(), Duration::from_millis(60));
+        app.insert_resource(AnimationTimeScale(time_scale));
+        let entity = spawn_animated(&mut app);
+        app.world_mut()
+            .entity_mut(entity)
+            .insert(AnimationSpeed(speed));
+        app.world_mut()
+            .resource_mut::<Animations>()
+            .insert_animation(
+                linear_timed("count", (10..18).collect(), LoopMode::Loop),
+                Some(entity),
+            );
+        app.world_mut()
+            .write_message(AnimationEvent("count", entity));
+        app.update();
+        (0..4)
+            .map(|_| {
+                app.update();
+                shown_index(&app, entity)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn speed_and_time_scale_multiply() {
+        assert_eq!(shown_with_speed(1., 1.), [10, 11, 11, 12]);
+        assert_eq!(shown_with_speed(2., 1.), [11, 12, 13, 14]);
+        assert_eq!(shown_with_speed(1., 2.), [11, 12, 13, 14]);
+        assert_eq!(shown_with_speed(2., 0.5), [10, 11, 11, 12]);
+        // Zero freezes the animation and negative values can't play it backwards
+        assert_eq!(shown_with_speed(0., 1.), [10; 4]);
+        assert_eq!(shown_with_speed(1., 0.), [10; 4]);
+        assert_eq!(shown_with_speed(-1., 1.), [10; 4]);
+    }
+
+    /// The atlas indexes systems in `Update` and after the [AnimationSystems] saw
+    #[derive(Resource, Default)]
+    struct SeenIndexes {
+        update: Vec<usize>,
+        after_animating: Vec<usize>,
+    }
+
+    #[test]
+    fn animation_systems_run_in_the_configured_schedule() {
+        let plugin = AnimationsPlugin::default().with_schedule(PostUpdate);
+        let mut app = animations_app(plugin, Duration::from_millis(130));
+        app.init_resource::<SeenIndexes>()
+            .add_systems(
+                Update,
+                |sprites: Query<&Sprite>, mut seen: ResMut<SeenIndexes>| {
+                    let sprite = sprites.single().unwrap();
+                    seen.update
+                        .push(sprite.texture_atlas.as_ref().unwrap().index);
+                },
+            )
+            .add_systems(
+                PostUpdate,
+                (|sprites: Query<&Sprite>, mut seen: ResMut<SeenIndexes>| {
+                    let sprite = sprites.single().unwrap();
+                    seen.after_animating
+                        .push(sprite.texture_atlas.as_ref().unwrap().index);
+                })
+                .after(AnimationSystems::Cleanup),
+            );
+        let entity = spawn_animated(&mut app);
+        app.world_mut()
+            .resource_mut::<Animations>()
+            .insert_animation(
+                linear_timed("count", vec![3, 4, 5], LoopMode::Once),
+                Some(entity),
+            );
+
+        app.world_mut()
+            .write_message(AnimationEvent("count", entity));
+        app.update();
+        app.update();
+        // `Update` runs before the animations so it sees the frame of the previous update
+        let seen = app.world().resource::<SeenIndexes>();
+        assert_eq!(seen.update, [0, 3]);
+        assert_eq!(seen.after_animating, [3, 4]);
+    }
+
+    /// Starts a looping animation on a new entity that counts up from 10 every 0.12 seconds of `time_source`
+    fn spawn_counting(
+        app: &mut App,
+        name: AnimationName,
+        time_source: Option<AnimationTimeSource>,
+    ) -> Entity {
+        let entity = spawn_animated(app);
+        let mut animation =
+            LinearTimedAnimation::new((10..20).collect(), vec![0.12; 10], LoopMode::Loop);
+        animation.time_source = time_source;
+        app.world_mut()
+            .resource_mut::<Animations>()
+            .insert_animation(
+                NewAnimation {
+                    handles: Handles::default(),
+                    animation: AnimationType::LinearTimed(animation, name),
+                },
+                Some(entity),
+            );
+        app.world_mut().write_message(AnimationEvent(name, entity));
+        entity
+    }
+
+    #[test]
+    fn real_time_animations_play_while_the_game_is_paused() {
+        let mut app = animations_app(AnimationsPlugin::default(), Duration::from_millis(130));
+        let real = spawn_counting(&mut app, "real", Some(AnimationTimeSource::Real));
+        let virtual_time = spawn_counting(&mut app, "virtual", None);
+        app.update();
+        app.world_mut().resource_mut::<Time<Virtual>>().pause();
+        // Real time isn't scaled either
+        app.insert_resource(AnimationTimeScale(0.5));
+        let mut shown = Vec::new();
+        for _ in 0..4 {
+            app.update();
+            shown.push((shown_index(&app, real), shown_index(&app, virtual_time)));
+        }
+        assert_eq!(shown, [(11, 10), (12, 10), (13, 10), (14, 10)]);
+    }
+
+    #[test]
+    fn fixed_time_animations_move_in_steps() {
+        let plugin = AnimationsPlugin::default().with_time_source(AnimationTimeSource::Fixed);
+        let mut app = animations_app(plugin, Duration::from_millis(130));
+        app.insert_resource(Time::<Fixed>::from_hz(20.));
+        let fixed = spawn_counting(&mut app, "fixed", None);
+        let virtual_time = spawn_counting(&mut app, "virtual", Some(AnimationTimeSource::Virtual));
+        app.update();
+        let mut shown = Vec::new();
+        for _ in 0..4 {
+            app.update();
+            shown.push((shown_index(&app, fixed), shown_index(&app, virtual_time)));
+        }
+        // The updates run 2, 3, 2 and 3 steps of 50ms so fixed time is at 0.1, 0.25, 0.35 and 0.5 seconds
+        assert_eq!(shown, [(10, 11), (12, 12), (12, 13), (14, 14)]);
+    }
+
+    #[test]
+    fn entities_sharing_an_animation_keep_their_own_progress() {
+        let mut app = animations_app(AnimationsPlugin::default(), Duration::from_millis(130));
+        let first = spawn_animated(&mut app);
+        let second = spawn_animated(&mut app);
+        let mut animations = app.world_mut().resource_mut::<Animations>();
+        animations.insert_animation(
+            linear_timed("walk", (10..18).collect(), LoopMode::Loop),
+            Some(first),
+        );
+        animations.add_animation_to_entity("walk", second).unwrap();
+        let shown = |app: &App| (shown_index(app, first), shown_index(app, second));
+
+        app.world_mut().write_message(AnimationEvent("walk", first));
+        app.update();
+        app.update();
+        app.update();
+        assert_eq!(shown(&app), (12, 0));
+
+        // The second entity starts from the first frame without moving the first one
+        app.world_mut()
+            .write_message(AnimationEvent("walk", second));
+        app.update();
+        assert_eq!(shown(&app), (13, 10));
+        app.update();
+        assert_eq!(shown(&app), (15, 11));
+
+        app.world_mut().write_message(PauseAnimation(first));
+        app.update();
+        assert_eq!(shown(&app), (15, 12));
+        let playback = app.world().get::<AnimationPlayback>(second).unwrap();
+        assert!(!playback.is_paused());
     }
-    entities_to_remove.0.clear();
 }
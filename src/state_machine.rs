@@ -0,0 +1,885 @@
+use bevy::asset::{io::Reader, AssetLoader, LoadContext};
+
+use crate::*;
+
+/// A parameter of an [AnimationStateMachine] set from gameplay code and checked by [AnimationCondition]s
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum AnimationParam {
+    Bool(bool),
+    Float(f32),
+    /// A bool that is reset once a transition uses it
+    Trigger(bool),
+}
+
+/// A condition a [AnimationTransition] needs to be true before it's taken
+#[derive(Debug, Clone, PartialEq)]
+pub enum AnimationCondition {
+    /// The bool parameter equals the value
+    Bool(&'static str, bool),
+    /// The float parameter is greater than the value
+    Greater(&'static str, f32),
+    /// The float parameter is less than the value
+    Less(&'static str, f32),
+    /// The trigger parameter is set. Taking the transition resets it
+    Trigger(&'static str),
+}
+
+impl AnimationCondition {
+    fn is_met(&self, params: &HashMap<&'static str, AnimationParam>) -> bool {
+        match self {
+            AnimationCondition::Bool(name, value) => {
+                params.get(name) == Some(&AnimationParam::Bool(*value))
+            }
+            AnimationCondition::Greater(name, value) => {
+                matches!(params.get(name), Some(AnimationParam::Float(param)) if param > value)
+            }
+            AnimationCondition::Less(name, value) => {
+                matches!(params.get(name), Some(AnimationParam::Float(param)) if param < value)
+            }
+            AnimationCondition::Trigger(name) => {
+                params.get(name) == Some(&AnimationParam::Trigger(true))
+            }
+        }
+    }
+}
+
+/// A transition between two states of an [AnimationStateGraph]
+///
+/// The transition is taken once every condition is met, and if it's [AnimationTransition::on_finished] the animation of the state it
+/// comes from has finished
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnimationTransition {
+    /// The state the transition leaves. [None] for a transition that can be taken from any state
+    pub from: Option<&'static str>,
+    pub to: &'static str,
+    pub conditions: Vec<AnimationCondition>,
+    pub on_finished: bool,
+}
+
+impl AnimationTransition {
+    pub fn new(from: &'static str, to: &'static str) -> Self {
+        Self {
+            from: Some(from),
+            to,
+            conditions: Vec::new(),
+            on_finished: false,
+        }
+    }
+
+    /// A transition that can be taken from any state of the graph it's in, except for the state it goes to
+    pub fn from_any(to: &'static str) -> Self {
+        Self {
+            from: None,
+            to,
+            conditions: Vec::new(),
+            on_finished: false,
+        }
+    }
+
+    /// Adds a condition to the transition. Every condition has to be met
+    pub fn when(mut self, condition: AnimationCondition) -> Self {
+        self.conditions.push(condition);
+        self
+    }
+
+    /// Only take the transition once the animation of the `from` state has finished
+    ///
+    /// **Note** animations with an endless [LoopMode] never finish
+    pub fn on_finished(mut self) -> Self {
+        self.on_finished = true;
+        self
+    }
+
+    fn can_leave(&self, state: &'static str) -> bool {
+        match self.from {
+            Some(from) => from == state,
+            None => self.to != state,
+        }
+    }
+}
+
+/// The states and transitions of an [AnimationStateMachine]
+///
+/// Every state plays an animation from the animation pool or is a sub-machine with its own states and transitions.
+/// The transitions are checked in the order they were added and the first one whose conditions are met is taken.
+/// The transitions of a graph are checked before the ones of its sub-machines, so a graph can always leave a sub-machine
+///
+/// Graphs can also be loaded from `.states.ron` or `.states.json` files and shared between entities with a [Handle]
+///
+/// # Example
+/// ```rust
+/// let graph = AnimationStateGraph::new("idle")
+///     .with_state("idle", "player_idle")
+///     .with_state("run", "player_running")
+///     .with_state("attack", "player_attack")
+///     .with_param("speed", AnimationParam::Float(0.))
+///     .with_param("attack", AnimationParam::Trigger(false))
+///     .with_transition(AnimationTransition::new("idle", "run").when(AnimationCondition::Greater("speed", 0.1)))
+///     .with_transition(AnimationTransition::new("run", "idle").when(AnimationCondition::Less("speed", 0.1)))
+///     .with_transition(AnimationTransition::new("idle", "attack").when(AnimationCondition::Trigger("attack")))
+///     .with_transition(AnimationTransition::new("attack", "idle").on_finished());
+/// ```
+#[derive(Debug, Clone, Default, Asset, TypePath)]
+pub struct AnimationStateGraph {
+    /// The state the machine starts in
+    pub initial_state: &'static str,
+    /// The animation each state plays
+    pub states: HashMap<&'static str, AnimationName>,
+    /// The states that are graphs of their own
+    pub sub_machines: HashMap<&'static str, AnimationStateGraph>,
+    pub transitions: Vec<AnimationTransition>,
+    /// The parameters and their starting values
+    pub params: HashMap<&'static str, AnimationParam>,
+}
+
+impl AnimationStateGraph {
+    pub fn new(initial_state: &'static str) -> Self {
+        Self {
+            initial_state,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_state(mut self, state: &'static str, animation: AnimationName) -> Self {
+        self.states.insert(state, animation);
+        self
+    }
+
+    /// Adds a state that is a graph of its own. Entering the state enters the initial state of `sub_machine`
+    pub fn with_sub_machine(
+        mut self,
+        state: &'static str,
+        sub_machine: AnimationStateGraph,
+    ) -> Self {
+        self.sub_machines.insert(state, sub_machine);
+        self
+    }
+
+    pub fn with_transition(mut self, transition: AnimationTransition) -> Self {
+        self.transitions.push(transition);
+        self
+    }
+
+    pub fn with_param(mut self, name: &'static str, value: AnimationParam) -> Self {
+        self.params.insert(name, value);
+        self
+    }
+
+    /// Gets the animation the state plays
+    pub fn get_animation(&self, state: &'static str) -> Option<AnimationName> {
+        self.states.get(state).copied()
+    }
+
+    /// Gets the graph at the end of `path`, where every entry is the name of a sub-machine in the graph before it
+    pub fn get_sub_machine(&self, path: &[&'static str]) -> Option<&AnimationStateGraph> {
+        let mut graph = self;
+        for state in path {
+            graph = graph.sub_machines.get(state)?;
+        }
+        Some(graph)
+    }
+
+    /// Adds the parameters of this graph and its sub-machines that aren't in `params` yet
+    fn insert_default_params(&self, params: &mut HashMap<&'static str, AnimationParam>) {
+        for (name, value) in self.params.iter() {
+            params.entry(name).or_insert(*value);
+        }
+        for sub_machine in self.sub_machines.values() {
+            sub_machine.insert_default_params(params);
+        }
+    }
+}
+
+/// Where the [AnimationStateGraph] of an [AnimationStateMachine] lives
+#[derive(Debug, Clone)]
+enum StateGraphSource {
+    Owned(Arc<AnimationStateGraph>),
+    Asset(Handle<AnimationStateGraph>),
+}
+
+/// A graph that doesn't keep its [AnimationStateMachine] borrowed
+pub(crate) enum StateGraphRef<'a> {
+    Owned(Arc<AnimationStateGraph>),
+    Asset(&'a AnimationStateGraph),
+}
+
+impl std::ops::Deref for StateGraphRef<'_> {
+    type Target = AnimationStateGraph;
+
+    fn deref(&self) -> &AnimationStateGraph {
+        match self {
+            StateGraphRef::Owned(graph) => graph,
+            StateGraphRef::Asset(graph) => graph,
+        }
+    }
+}
+
+/// Picks the animation of an entity from an [AnimationStateGraph] and the parameters you set
+///
+/// The [AnimationsPlugin] checks the transitions of every state machine each frame and sends the [AnimationEvent] for the
+/// current state, so there is no need to send them yourself. The entity still needs to be added to [Animations] along with
+/// every animation its states use
+///
+/// Every state machine has its own parameters, even when the graph is shared through a [Handle]
+///
+/// # Example
+/// ```rust
+/// fn spawn_player(mut commands: Commands) {
+///     commands.spawn((
+///         Animator::default(),
+///         Sprite::default(),
+///         AnimationStateMachine::new(graph), /* the graph from the AnimationStateGraph example */
+///     ));
+/// }
+///
+/// fn move_player(mut query: Query<&mut AnimationStateMachine, With<Player>>, keys: Res<ButtonInput<KeyCode>>) {
+///     let mut state_machine = query.single_mut().unwrap();
+///     state_machine.set_float("speed", /* your speed */);
+///     if keys.just_pressed(KeyCode::Space) {
+///         state_machine.set_trigger("attack");
+///     }
+/// }
+/// ```
+#[derive(Debug, Component, Clone)]
+pub struct AnimationStateMachine {
+    graph: StateGraphSource,
+    params: HashMap<&'static str, AnimationParam>,
+    /// The current state of every graph from the root graph down to the one playing an animation
+    curr_path: Vec<&'static str>,
+    curr_animation: Option<AnimationName>,
+    /// A state requested with [AnimationStateMachine::set_state] that is entered on the next update
+    requested_state: Option<&'static str>,
+    /// If the animation of the current state has started on the entity
+    playing: bool,
+    /// If the animation of the current state has started and then stopped
+    finished: bool,
+}
+
+impl AnimationStateMachine {
+    pub fn new(graph: AnimationStateGraph) -> Self {
+        Self::from_source(StateGraphSource::Owned(Arc::new(graph)))
+    }
+
+    /// Uses a graph loaded as an asset. The machine starts once the graph has loaded
+    pub fn from_handle(graph: Handle<AnimationStateGraph>) -> Self {
+        Self::from_source(StateGraphSource::Asset(graph))
+    }
+
+    fn from_source(graph: StateGraphSource) -> Self {
+        Self {
+            graph,
+            params: HashMap::new(),
+            curr_path: Vec::new(),
+            curr_animation: None,
+            requested_state: None,
+            playing: false,
+            finished: false,
+        }
+    }
+
+    /// The handle of the graph if it's an asset
+    pub fn handle(&self) -> Option<&Handle<AnimationStateGraph>> {
+        match &self.graph {
+            StateGraphSource::Asset(handle) => Some(handle),
+            StateGraphSource::Owned(_) => None,
+        }
+    }
+
+    /// Gets the graph of the machine
+    ///
+    /// Returns [None] if the graph is an asset that hasn't loaded
+    pub fn graph<'a>(
+        &'a self,
+        graphs: &'a Assets<AnimationStateGraph>,
+    ) -> Option<&'a AnimationStateGraph> {
+        match &self.graph {
+            StateGraphSource::Owned(graph) => Some(graph),
+            StateGraphSource::Asset(handle) => graphs.get(handle),
+        }
+    }
+
+    /// Like [AnimationStateMachine::graph] but the state machine can be changed while the graph is used
+    pub(crate) fn graph_ref<'a>(
+        &self,
+        graphs: &'a Assets<AnimationStateGraph>,
+    ) -> Option<StateGraphRef<'a>> {
+        match &self.graph {
+            StateGraphSource::Owned(graph) => Some(StateGraphRef::Owned(Arc::clone(graph))),
+            StateGraphSource::Asset(handle) => graphs.get(handle.id()).map(StateGraphRef::Asset),
+        }
+    }
+
+    /// The state playing an animation. If it's in a sub-machine this is the state inside the sub-machine
+    ///
+    /// Returns [None] before the machine has started
+    pub fn current_state(&self) -> Option<&'static str> {
+        self.curr_path.last().copied()
+    }
+
+    /// The current state of every graph from the root graph down to the state playing an animation
+    pub fn current_path(&self) -> &[&'static str] {
+        &self.curr_path
+    }
+
+    /// The animation of the current state
+    ///
+    /// Returns [None] before the machine has started
+    pub fn current_animation(&self) -> Option<AnimationName> {
+        self.curr_animation
+    }
+
+    pub fn set_bool(&mut self, name: &'static str, value: bool) -> &mut Self {
+        self.params.insert(name, AnimationParam::Bool(value));
+        self
+    }
+
+    pub fn set_float(&mut self, name: &'static str, value: f32) -> &mut Self {
+        self.params.insert(name, AnimationParam::Float(value));
+        self
+    }
+
+    /// Sets the trigger until a transition uses it
+    pub fn set_trigger(&mut self, name: &'static str) -> &mut Self {
+        self.params.insert(name, AnimationParam::Trigger(true));
+        self
+    }
+
+    pub fn reset_trigger(&mut self, name: &'static str) -> &mut Self {
+        self.params.insert(name, AnimationParam::Trigger(false));
+        self
+    }
+
+    pub fn get_param(&self, name: &'static str) -> Option<AnimationParam> {
+        self.params.get(name).copied()
+    }
+
+    pub fn get_bool(&self, name: &'static str) -> Option<bool> {
+        match self.params.get(name) {
+            Some(AnimationParam::Bool(value)) => Some(*value),
+            _ => None,
+        }
+    }
+
+    pub fn get_float(&self, name: &'static str) -> Option<f32> {
+        match self.params.get(name) {
+            Some(AnimationParam::Float(value)) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Moves the machine to a state of the root graph on the next update without checking any transitions
+    pub fn set_state(&mut self, state: &'static str) -> &mut Self {
+        self.requested_state = Some(state);
+        self
+    }
+
+    /// Starts the machine and enters the state requested with [AnimationStateMachine::set_state]
+    ///
+    /// Restarts the machine if the graph changed and the current state doesn't exist anymore
+    pub(crate) fn update_state(&mut self, graph: &AnimationStateGraph) {
+        let curr_state_exists = self.curr_path.split_last().is_some_and(|(state, path)| {
+            graph
+                .get_sub_machine(path)
+                .is_some_and(|sub_machine| sub_machine.states.contains_key(state))
+        });
+        if !curr_state_exists {
+            graph.insert_default_params(&mut self.params);
+            self.enter(graph, 0, graph.initial_state);
+        }
+        if let Some(state) = self.requested_state.take() {
+            self.enter(graph, 0, state);
+        }
+    }
+
+    /// Updates if the current state's animation is playing or has finished
+    pub(crate) fn update_playing(&mut self, doing_animation: bool) {
+        if doing_animation {
+            self.playing = true;
+        } else if self.playing {
+            self.finished = true;
+        }
+    }
+
+    /// If the current state's animation needs to be started with an [AnimationEvent]
+    pub(crate) fn needs_start(&self) -> bool {
+        !self.playing
+    }
+
+    /// Takes the first transition that can be taken, starting with the root graph
+    pub(crate) fn take_transition(&mut self, graph: &AnimationStateGraph) {
+        for depth in 0..self.curr_path.len() {
+            let Some(sub_machine) = graph.get_sub_machine(&self.curr_path[..depth]) else {
+                return;
+            };
+            let state = self.curr_path[depth];
+            let transition = sub_machine.transitions.iter().find(|transition| {
+                transition.can_leave(state)
+                    && (!transition.on_finished || self.finished)
+                    && transition
+                        .conditions
+                        .iter()
+                        .all(|condition| condition.is_met(&self.params))
+            });
+            let Some(transition) = transition else {
+                continue;
+            };
+            for condition in transition.conditions.iter() {
+                if let AnimationCondition::Trigger(name) = condition {
+                    self.params.insert(name, AnimationParam::Trigger(false));
+                }
+            }
+            self.enter(graph, depth, transition.to);
+            return;
+        }
+    }
+
+    /// Enters `state` of the graph at `depth` of the current path, and the initial states of any sub-machines it leads to
+    ///
+    /// Stays in the current state if `state` or the initial state of a sub-machine it leads to isn't in the graph
+    fn enter(&mut self, graph: &AnimationStateGraph, depth: usize, state: &'static str) {
+        let mut path = self.curr_path[..depth.min(self.curr_path.len())].to_vec();
+        let Some(mut sub_machine) = graph.get_sub_machine(&path) else {
+            warn!("The state machine couldn't find the sub-machine {:?}", path);
+            return;
+        };
+        let mut state = state;
+        while let Some(next) = sub_machine.sub_machines.get(state) {
+            path.push(state);
+            sub_machine = next;
+            state = next.initial_state;
+        }
+        let Some(animation) = sub_machine.get_animation(state) else {
+            warn!("State {state} not found make sure it was added to the `AnimationStateGraph`");
+            return;
+        };
+        path.push(state);
+        self.curr_path = path;
+        self.curr_animation = Some(animation);
+        self.playing = false;
+        self.finished = false;
+    }
+}
+
+/// Loads an [AnimationStateGraph] from RON or JSON depending on the file extension
+///
+/// # Example
+/// ```ron
+/// (
+///     initial_state: "locomotion",
+///     params: { "speed": Float(0.), "attack": Trigger(false), "hurt": Trigger(false) },
+///     states: { "hurt": "player_hurt" },
+///     sub_machines: {
+///         "locomotion": (
+///             initial_state: "idle",
+///             states: { "idle": "player_idle", "run": "player_running" },
+///             transitions: [
+///                 (from: "idle", to: "run", conditions: [Greater("speed", 0.1)]),
+///                 (from: "run", to: "idle", conditions: [Less("speed", 0.1)]),
+///             ],
+///         ),
+///         "combat": (
+///             initial_state: "slash",
+///             states: { "slash": "player_slash" },
+///         ),
+///     },
+///     transitions: [
+///         (from: "locomotion", to: "combat", conditions: [Trigger("attack")]),
+///         (from: "combat", to: "locomotion", on_finished: true),
+///         (from: "hurt", to: "locomotion", on_finished: true),
+///         (to: "hurt", conditions: [Trigger("hurt")]), /* no `from` means any state */
+///     ],
+/// )
+/// ```
+#[derive(Debug, Default, TypePath)]
+pub struct AnimationStateGraphLoader;
+
+impl AssetLoader for AnimationStateGraphLoader {
+    type Asset = AnimationStateGraph;
+    type Settings = ();
+    type Error = AnimationLibraryError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<AnimationStateGraph, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let is_json = load_context
+            .path()
+            .get_full_extension()
+            .is_some_and(|extension| extension.ends_with("json"));
+        let definition: StateGraphDefinition = if is_json {
+            serde_json::from_slice(&bytes)?
+        } else {
+            from_ron(&bytes)?
+        };
+        definition.into_graph()
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["states.ron", "states.json"]
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct StateGraphDefinition {
+    initial_state: String,
+    #[serde(default)]
+    states: HashMap<String, String>,
+    #[serde(default)]
+    sub_machines: HashMap<String, StateGraphDefinition>,
+    #[serde(default)]
+    transitions: Vec<TransitionDefinition>,
+    #[serde(default)]
+    params: HashMap<String, AnimationParam>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TransitionDefinition {
+    #[serde(default)]
+    from: Option<String>,
+    to: String,
+    #[serde(default)]
+    conditions: Vec<ConditionDefinition>,
+    #[serde(default)]
+    on_finished: bool,
+}
+
+#[derive(Debug, Deserialize)]
+enum ConditionDefinition {
+    Bool(String, bool),
+    Greater(String, f32),
+    Less(String, f32),
+    Trigger(String),
+}
+
+impl StateGraphDefinition {
+    fn into_graph(self) -> Result<AnimationStateGraph, AnimationLibraryError> {
+        let mut graph = AnimationStateGraph::new(intern_animation_name(&self.initial_state));
+        for (state, animation) in self.states {
+            graph.states.insert(
+                intern_animation_name(&state),
+                intern_animation_name(&animation),
+            );
+        }
+        for (state, sub_machine) in self.sub_machines {
+            graph
+                .sub_machines
+                .insert(intern_animation_name(&state), sub_machine.into_graph()?);
+        }
+        for (name, value) in self.params {
+            graph.params.insert(intern_animation_name(&name), value);
+        }
+
+        let has_state = |state: &str| {
+            graph.states.contains_key(state) || graph.sub_machines.contains_key(state)
+        };
+        if !has_state(&self.initial_state) {
+            return Err(AnimationLibraryError::InvalidAnimation(format!(
+                "The initial state `{}` doesn't exist",
+                self.initial_state
+            )));
+        }
+        for transition in self.transitions.iter() {
+            for state in transition.from.iter().chain([&transition.to]) {
+                if !has_state(state) {
+                    return Err(AnimationLibraryError::InvalidAnimation(format!(
+                        "A transition uses the state `{state}` which doesn't exist"
+                    )));
+                }
+            }
+        }
+
+        for transition in self.transitions {
+            let conditions = transition
+                .conditions
+                .into_iter()
+                .map(|condition| match condition {
+                    ConditionDefinition::Bool(name, value) => {
+                        AnimationCondition::Bool(intern_animation_name(&name), value)
+                    }
+                    ConditionDefinition::Greater(name, value) => {
+                        AnimationCondition::Greater(intern_animation_name(&name), value)
+                    }
+                    ConditionDefinition::Less(name, value) => {
+                        AnimationCondition::Less(intern_animation_name(&name), value)
+                    }
+                    ConditionDefinition::Trigger(name) => {
+                        AnimationCondition::Trigger(intern_animation_name(&name))
+                    }
+                })
+                .collect();
+            graph.transitions.push(AnimationTransition {
+                from: transition.from.as_deref().map(intern_animation_name),
+                to: intern_animation_name(&transition.to),
+                conditions,
+                on_finished: transition.on_finished,
+            });
+        }
+        Ok(graph)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NESTED_RON: &str = r#"(
+        initial_state: "locomotion",
+        params: { "speed": Float(0.), "attack": Trigger(false) },
+        sub_machines: {
+            "locomotion": (
+                initial_state: "idle",
+                states: { "idle": "player_idle", "run": "player_running" },
+                transitions: [
+                    (from: "idle", to: "run", conditions: [Greater("speed", 0.1)]),
+                    (from: "run", to: "idle", conditions: [Less("speed", 0.1)]),
+                ],
+            ),
+            "combat": (
+                initial_state: "slash",
+                states: { "slash": "player_slash" },
+            ),
+        },
+        transitions: [
+            (from: "locomotion", to: "combat", conditions: [Trigger("attack")]),
+            (from: "combat", to: "locomotion", on_finished: true),
+        ],
+    )"#;
+
+    const MISSING_INITIAL_STATE_JSON: &str = r#"{
+        "initial_state": "jump",
+        "states": { "idle": "player_idle" }
+    }"#;
+
+    const UNKNOWN_TRANSITION_TARGET_JSON: &str = r#"{
+        "initial_state": "idle",
+        "states": { "idle": "player_idle" },
+        "transitions": [{ "from": "idle", "to": "fly" }]
+    }"#;
+
+    const NESTED_MISSING_INITIAL_STATE_RON: &str = r#"(
+        initial_state: "locomotion",
+        sub_machines: {
+            "locomotion": (initial_state: "walk", states: { "idle": "player_idle" }),
+        },
+    )"#;
+
+    fn parse_ron(source: &str) -> Result<AnimationStateGraph, AnimationLibraryError> {
+        from_ron::<StateGraphDefinition>(source.as_bytes())?.into_graph()
+    }
+
+    fn parse_json(source: &str) -> Result<AnimationStateGraph, AnimationLibraryError> {
+        serde_json::from_str::<StateGraphDefinition>(source)?.into_graph()
+    }
+
+    fn invalid_message(result: Result<AnimationStateGraph, AnimationLibraryError>) -> String {
+        match result {
+            Err(AnimationLibraryError::InvalidAnimation(message)) => message,
+            other => panic!("Expected an invalid graph, got {other:?}"),
+        }
+    }
+
+    fn graph() -> AnimationStateGraph {
+        AnimationStateGraph::new("idle")
+            .with_state("idle", "player_idle")
+            .with_state("run", "player_running")
+            .with_state("attack", "player_attack")
+            .with_state("hurt", "player_hurt")
+            .with_param("speed", AnimationParam::Float(0.))
+            .with_param("attack", AnimationParam::Trigger(false))
+            .with_transition(
+                AnimationTransition::new("idle", "run")
+                    .when(AnimationCondition::Greater("speed", 0.1)),
+            )
+            .with_transition(
+                AnimationTransition::new("run", "idle")
+                    .when(AnimationCondition::Less("speed", 0.1)),
+            )
+            .with_transition(
+                AnimationTransition::new("idle", "attack")
+                    .when(AnimationCondition::Trigger("attack")),
+            )
+            .with_transition(AnimationTransition::new("attack", "idle").on_finished())
+            .with_transition(
+                AnimationTransition::from_any("hurt").when(AnimationCondition::Bool("hurt", true)),
+            )
+            .with_transition(AnimationTransition::new("hurt", "idle").on_finished())
+    }
+
+    /// A machine that has entered the initial state of `graph` and started its animation
+    fn started(graph: &AnimationStateGraph) -> AnimationStateMachine {
+        let mut state_machine = AnimationStateMachine::new(graph.clone());
+        state_machine.update_state(graph);
+        state_machine.update_playing(true);
+        state_machine
+    }
+
+    #[test]
+    fn conditions_check_the_params() {
+        let mut params = HashMap::new();
+        params.insert("grounded", AnimationParam::Bool(true));
+        params.insert("speed", AnimationParam::Float(2.));
+        params.insert("jump", AnimationParam::Trigger(false));
+
+        assert!(AnimationCondition::Bool("grounded", true).is_met(&params));
+        assert!(!AnimationCondition::Bool("grounded", false).is_met(&params));
+        assert!(AnimationCondition::Greater("speed", 1.).is_met(&params));
+        assert!(!AnimationCondition::Greater("speed", 2.).is_met(&params));
+        assert!(AnimationCondition::Less("speed", 3.).is_met(&params));
+        assert!(!AnimationCondition::Less("speed", 2.).is_met(&params));
+        assert!(!AnimationCondition::Trigger("jump").is_met(&params));
+        params.insert("jump", AnimationParam::Trigger(true));
+        assert!(AnimationCondition::Trigger("jump").is_met(&params));
+        // A missing param or one of another kind is never met
+        assert!(!AnimationCondition::Bool("missing", false).is_met(&params));
+        assert!(!AnimationCondition::Greater("grounded", 0.).is_met(&params));
+    }
+
+    #[test]
+    fn transitions_follow_the_params() {
+        let graph = graph();
+        let mut state_machine = started(&graph);
+        assert_eq!(state_machine.current_state(), Some("idle"));
+        assert_eq!(state_machine.get_float("speed"), Some(0.));
+
+        state_machine.set_float("speed", 1.);
+        state_machine.take_transition(&graph);
+        assert_eq!(state_machine.current_state(), Some("run"));
+        assert_eq!(state_machine.current_animation(), Some("player_running"));
+        assert!(state_machine.needs_start());
+
+        state_machine.set_float("speed", 0.);
+        state_machine.take_transition(&graph);
+        assert_eq!(state_machine.current_state(), Some("idle"));
+    }
+
+    #[test]
+    fn taking_a_transition_consumes_its_trigger() {
+        let graph = graph();
+        let mut state_machine = started(&graph);
+        state_machine.set_trigger("attack");
+        state_machine.take_transition(&graph);
+        assert_eq!(state_machine.current_state(), Some("attack"));
+        assert_eq!(
+            state_machine.get_param("attack"),
+            Some(AnimationParam::Trigger(false))
+        );
+    }
+
+    #[test]
+    fn on_finished_waits_for_the_animation_to_stop() {
+        let graph = graph();
+        let mut state_machine = started(&graph);
+        state_machine.set_trigger("attack");
+        state_machine.take_transition(&graph);
+
+        // Not started yet, so it can't have finished
+        state_machine.update_playing(false);
+        state_machine.take_transition(&graph);
+        assert_eq!(state_machine.current_state(), Some("attack"));
+        state_machine.update_playing(true);
+        state_machine.take_transition(&graph);
+        assert_eq!(state_machine.current_state(), Some("attack"));
+
+        state_machine.update_playing(false);
+        state_machine.take_transition(&graph);
+        assert_eq!(state_machine.current_state(), Some("idle"));
+    }
+
+    #[test]
+    fn any_state_transitions_skip_their_own_target() {
+        let graph = graph();
+        let mut state_machine = started(&graph);
+        state_machine.set_bool("hurt", true);
+        state_machine.take_transition(&graph);
+        assert_eq!(state_machine.current_state(), Some("hurt"));
+
+        // Staying hurt doesn't restart the hurt animation
+        state_machine.update_playing(true);
+        state_machine.take_transition(&graph);
+        assert_eq!(state_machine.current_state(), Some("hurt"));
+        assert!(!state_machine.needs_start());
+    }
+
+    #[test]
+    fn restarts_when_the_current_state_is_removed_from_the_graph() {
+        let graph = graph();
+        let mut state_machine = started(&graph);
+        state_machine.set_float("speed", 1.);
+        state_machine.take_transition(&graph);
+        assert_eq!(state_machine.current_state(), Some("run"));
+
+        let changed = AnimationStateGraph::new("walk")
+            .with_state("idle", "player_idle")
+            .with_state("walk", "player_walking");
+        state_machine.update_state(&changed);
+        assert_eq!(state_machine.current_state(), Some("walk"));
+        assert_eq!(state_machine.current_animation(), Some("player_walking"));
+        // The params that were set are kept
+        assert_eq!(state_machine.get_float("speed"), Some(1.));
+
+        // A state that's still in the graph is kept
+        state_machine.set_state("idle");
+        state_machine.update_state(&changed);
+        state_machine.update_state(&graph);
+        assert_eq!(state_machine.current_state(), Some("idle"));
+    }
+
+    #[test]
+    fn unknown_states_are_skipped() {
+        let graph = graph();
+        let mut state_machine = started(&graph);
+        state_machine.set_state("missing");
+        state_machine.update_state(&graph);
+        assert_eq!(state_machine.current_state(), Some("idle"));
+        assert!(!state_machine.needs_start());
+
+        let broken = AnimationStateGraph::new("missing");
+        let mut state_machine = AnimationStateMachine::new(broken.clone());
+        state_machine.update_state(&broken);
+        assert_eq!(state_machine.current_state(), None);
+        assert_eq!(state_machine.current_animation(), None);
+    }
+
+    #[test]
+    fn loaded_graphs_need_their_initial_state() {
+        let message = invalid_message(parse_json(MISSING_INITIAL_STATE_JSON));
+        assert!(message.contains("`jump`"), "{message}");
+        let message = invalid_message(parse_ron(NESTED_MISSING_INITIAL_STATE_RON));
+        assert!(message.contains("`walk`"), "{message}");
+    }
+
+    #[test]
+    fn loaded_transitions_need_existing_states() {
+        let message = invalid_message(parse_json(UNKNOWN_TRANSITION_TARGET_JSON));
+        assert!(message.contains("`fly`"), "{message}");
+    }
+
+    #[test]
+    fn loaded_sub_machines_enter_and_leave_through_the_parent() {
+        let graph = parse_ron(NESTED_RON).unwrap();
+        let mut state_machine = started(&graph);
+        assert_eq!(state_machine.current_path(), ["locomotion", "idle"]);
+        assert_eq!(state_machine.current_animation(), Some("player_idle"));
+
+        state_machine.set_float("speed", 1.);
+        state_machine.take_transition(&graph);
+        assert_eq!(state_machine.current_path(), ["locomotion", "run"]);
+
+        // The root graph's transitions are checked first, so the attack leaves the sub-machine from any of its states
+        state_machine.update_playing(true);
+        state_machine.set_trigger("attack");
+        state_machine.take_transition(&graph);
+        assert_eq!(state_machine.current_path(), ["combat", "slash"]);
+        assert_eq!(state_machine.current_animation(), Some("player_slash"));
+
+        state_machine.update_playing(true);
+        state_machine.update_playing(false);
+        state_machine.take_transition(&graph);
+        // Coming back enters the initial state of the sub-machine, not the state it was left in
+        assert_eq!(state_machine.current_path(), ["locomotion", "idle"]);
+    }
+}
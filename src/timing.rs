@@ -0,0 +1,79 @@
+use crate::*;
+
+/// The clock timed animations advance with
+///
+/// # Example
+/// ```rust
+/// // the sparkles of the pause menu keep playing while the game is paused
+/// LinearTimedAnimation::new(
+///     Vec::from(SPARKLE_FRAMES),
+///     Vec::from(SPARKLE_TIMINGS),
+///     LoopMode::Loop,
+/// )
+/// .with_time_source(AnimationTimeSource::Real)
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AnimationTimeSource {
+    /// `Time<Virtual>`, which stops when the game is paused and follows its relative speed
+    #[default]
+    Virtual,
+    /// `Time<Real>`, which keeps going while the game is paused. It isn't scaled by the [AnimationTimeScale] either
+    Real,
+    /// `Time<Fixed>`, which moves in steps of its timestep
+    Fixed,
+}
+
+/// The time that passed for an animation since it was last cycled
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct AnimationDelta {
+    /// The frame time scaled by the [AnimationTimeScale] and the [AnimationSpeed] of the entity
+    pub time: Duration,
+    /// The whole steps of `Time<Fixed>` since the last update, used by animations timed in ticks
+    pub ticks: u32,
+}
+
+/// How many steps of `Time<Fixed>` have run. Counting the steps as they run keeps the ticks exact even if the timestep changes
+#[derive(Resource, Debug, Default)]
+pub(crate) struct FixedStepCount(pub(crate) u64);
+
+pub(crate) fn count_fixed_steps(mut fixed_steps: ResMut<FixedStepCount>) {
+    fixed_steps.0 += 1;
+}
+
+/// Times frames in whole game ticks so the same frame data always plays back exactly the same
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct TickTimer {
+    elapsed: u32,
+    duration: u32,
+}
+
+impl TickTimer {
+    /// Adds `ticks` to the ticks built up for the current frame
+    pub(crate) fn accumulate(&mut self, ticks: u32) {
+        self.elapsed = self.elapsed.saturating_add(ticks);
+    }
+
+    /// Takes the ticks of the current frame out of the built up ticks
+    ///
+    /// Returns `false` if not enough ticks have built up yet
+    pub(crate) fn take_frame(&mut self) -> bool {
+        let Some(left_over) = self.elapsed.checked_sub(self.duration) else {
+            return false;
+        };
+        self.elapsed = left_over;
+        true
+    }
+
+    pub(crate) fn set_duration(&mut self, duration: u32) {
+        self.duration = duration;
+    }
+
+    /// Drops the ticks built up past the duration of the current frame
+    pub(crate) fn clamp_to_duration(&mut self) {
+        self.elapsed = self.elapsed.min(self.duration);
+    }
+
+    pub(crate) fn reset(&mut self) {
+        self.elapsed = 0;
+    }
+}
@@ -26,34 +26,34 @@ pub type AnimationName = &'static str;
 ///     mut commands: Commands,
 ///     mut animations: ResMut<Animations>
 /// ) {
-///     let entity = commands.spawn(
-///         AnimationDirection::Still // the `AnimationDirection` component is needed on the entity to determine the direction
-///         SpriteSheetBundle {
-///             atlas: // your texture atlas
-///             transform: Transform::from_xyz(0., 0., 0.), // your desired location in the `World`
-///             ..Default::default()
-///         }
+///     let entity = commands.spawn((
+///         Animator::default(), // the `Animator` component is needed on the entity to determine the direction
+///         Sprite::from_atlas_image(image.clone(), TextureAtlas::from(layout.clone())), // your sprite sheet
+///         Transform::from_xyz(0., 0., 0.), // your desired location in the `World`
 ///         /* The rest of your entity configuration */
-///     );
+///     )).id();
 ///
 ///     animations.insert_animation(
-///         entity.id(),
-///         AnimationType::Transform(
-///             TransformAnimation::new(
-///                 /* animation_frames */ vec![0, 1, 2, 3] // the x index for your frames to cycle through
-///                 /* meters per frame */ 0.55 // your desired meters per frame
-///                 /* handle */ texture_atlas_handle // your sprite sheet
-///                 /* frame */ Vec2::new(4., 4.) // the length and height of your sprite sheet
-///                 /* direction_indexes */ AnimationDirectionIndexes::new(4, 3, 2, 1) // from the example above
-///                 /* repeating */ true // if the animation is repeating or not
-///             )
-///         )
-///     )
+///         NewAnimation {
+///             handles: Handles::new(image, layout), // your sprite sheet and its layout
+///             animation: AnimationType::Transform(
+///                 TransformAnimation::new(
+///                     /* animation_frames */ vec![0, 1, 2, 3], // the x index for your frames to cycle through
+///                     /* meters per frame */ 0.55, // your desired meters per frame
+///                     /* frame */ Vec2::new(4., 4.), // the length and height of your sprite sheet
+///                     /* direction_indexes */ AnimationDirectionIndexes::IndexBased(IndexBasedDirection { left: 4, right: 3, up: 2, down: 1 }), // from the example above
+///                     /* loop_mode */ LoopMode::Loop, // what the animation does after its last frame
+///                 ),
+///                 "player_running",
+///             ),
+///         },
+///         Some(entity),
+///     );
 /// }
 /// ```
 ///
 /// **Note** how the `animation_frames` field from the animation definition above is 0th index based
-#[derive(Clone, Debug, Copy)]
+#[derive(Clone, Debug, Copy, Serialize, Deserialize)]
 pub enum AnimationDirectionIndexes {
     IndexBased(IndexBasedDirection),
     FlipBased(FlipBasedDirection),
@@ -82,7 +82,7 @@ impl Default for AnimationDirectionIndexes {
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub struct FXBasedDirection {
     /// The y-index of the FX animation. 0th index Based
     pub index: usize,
@@ -93,7 +93,7 @@ pub struct FXBasedDirection {
 ///
 /// **Note** if you have each direction already in a sprite sheet use `IndexBasedDirection` as it
 /// is functionally more proper
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub struct FlipBasedDirection {
     /// To Determine if the Left Facing Sprites are Left Facing When Flipped or Not
     pub left_direction_is_flipped: bool,
@@ -105,7 +105,7 @@ pub struct FlipBasedDirection {
 ///
 /// **Note** for this functionality to work properly your sprite sheet should be formatted in a certain way
 /// It should have frames for each direction you need as the y index on the grid.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub struct IndexBasedDirection {
     /// The Y index on the Sprite Sheet for Left Facing Sprites. 0th index Based
     pub left: usize,
@@ -126,10 +126,10 @@ pub enum YIndex {
 #[derive(Debug, Component, Clone, Default)]
 pub struct Animation {
     pub handles: Handles,
-    pub animation: Arc<Mutex<AnimationType>>,
+    pub animation: Arc<AnimationType>,
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, PartialEq)]
 pub struct Handles {
     image: Handle<Image>,
     layout: Handle<TextureAtlasLayout>,
@@ -139,6 +139,14 @@ impl Handles {
     pub fn new(image: Handle<Image>, layout: Handle<TextureAtlasLayout>) -> Self {
         Self { image, layout }
     }
+    /// Creates the layout for `grid` and adds it to `layouts`
+    pub fn from_grid(
+        image: Handle<Image>,
+        grid: &SheetGrid,
+        layouts: &mut Assets<TextureAtlasLayout>,
+    ) -> Self {
+        Self::new(image, layouts.add(grid.layout()))
+    }
     /// Returns a clone of the reference counted handle
     pub fn image(&self) -> Handle<Image> {
         self.image.clone()
@@ -149,7 +157,121 @@ impl Handles {
     }
 }
 
-#[derive(Default, Resource, Debug)]
+/// Describes a sprite sheet where every frame is a cell in a grid
+///
+/// Use [SheetGrid::layout] to create the [TextureAtlasLayout] for the sheet and pass the same grid as the `frame` of an animation,
+/// that way the layout and the atlas indexes the animation calculates always agree
+///
+/// # Example
+/// ```rust
+/// let grid = SheetGrid::new(UVec2::new(16, 16), 4, 4);
+/// let layout = layouts.add(grid.layout());
+///
+/// let animation = TimedAnimation::new(
+///     vec![0, 1, 2, 3],
+///     vec![0.1, 0.1, 0.1, 0.1],
+///     grid,
+///     AnimationDirectionIndexes::one_directional(),
+///     true,
+///     false,
+///     0,
+/// );
+/// ```
+///
+/// **Note** a `Vec2` of columns and rows still works anywhere a [SheetGrid] is expected, but it can't create a layout
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct SheetGrid {
+    /// The size of a single frame in pixels
+    pub cell_size: UVec2,
+    /// The number of frames in each row
+    pub columns: u32,
+    /// The number of rows
+    pub rows: u32,
+    /// The space between frames in pixels
+    pub padding: Option<UVec2>,
+    /// The space between the top left corner of the sheet and the first frame in pixels
+    pub offset: Option<UVec2>,
+}
+
+impl SheetGrid {
+    pub fn new(cell_size: UVec2, columns: u32, rows: u32) -> Self {
+        Self {
+            cell_size,
+            columns,
+            rows,
+            padding: None,
+            offset: None,
+        }
+    }
+
+    pub fn with_padding(mut self, padding: UVec2) -> Self {
+        self.padding = Some(padding);
+        self
+    }
+
+    pub fn with_offset(mut self, offset: UVec2) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Creates the [TextureAtlasLayout] of the sheet
+    pub fn layout(&self) -> TextureAtlasLayout {
+        TextureAtlasLayout::from_grid(
+            self.cell_size,
+            self.columns,
+            self.rows,
+            self.padding,
+            self.offset,
+        )
+    }
+
+    /// The atlas index of the frame in column `x` of row `y`. Both are 0th index based
+    pub fn index(&self, x: usize, y: usize) -> usize {
+        y * self.columns as usize + x
+    }
+
+    /// The atlas indexes of `(column, row)` cells. Both are 0th index based
+    pub fn cell_indexes(&self, cells: &[(usize, usize)]) -> Vec<usize> {
+        cells.iter().map(|(x, y)| self.index(*x, *y)).collect()
+    }
+
+    /// The number of frames in the sheet
+    pub fn len(&self) -> usize {
+        self.columns as usize * self.rows as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Only the columns and rows are known, so the grid can be used for the index math but [SheetGrid::layout] will be empty
+impl From<Vec2> for SheetGrid {
+    fn from(frame: Vec2) -> Self {
+        Self {
+            columns: frame.x as u32,
+            rows: frame.y as u32,
+            ..Default::default()
+        }
+    }
+}
+
+/// Where a single frame of a packed sprite sheet should be anchored
+///
+/// Packed sheets trim the empty space around each frame, so without this the sprite would jitter as the frames change size.
+/// Insert them with [Animations::insert_frame_pivots] and the [Anchor](bevy::sprite::Anchor) of every sprite using the layout
+/// is kept up to date with the frame it is showing
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct FramePivot {
+    /// The anchor of the frame as it is stored in the sheet
+    pub anchor: Vec2,
+    /// If the frame is stored rotated 90 degrees clockwise in the sheet.
+    /// The sprite and its children are rotated back while this frame is shown. The [Transform] only has the rotation while
+    /// transforms are propagated, so your systems never see it
+    pub rotated: bool,
+}
+
+#[derive(Default, Resource, Debug, Clone)]
 pub struct NewAnimation {
     pub handles: Handles,
     pub animation: AnimationType,
@@ -183,21 +305,21 @@ impl AnimationType {
     //         AnimationType::None => panic!("Something went terribly wrong"),
     //     }
     // }
-    pub fn timed_animation(&mut self) -> Option<&mut TimedAnimation> {
+    pub fn timed_animation(&self) -> Option<&TimedAnimation> {
         match self {
             AnimationType::Timed(timed_animation, _) => Some(timed_animation),
             _ => None,
         }
     }
 
-    pub fn linear_timed_animation(&mut self) -> Option<&mut LinearTimedAnimation> {
+    pub fn linear_timed_animation(&self) -> Option<&LinearTimedAnimation> {
         match self {
             AnimationType::LinearTimed(linear_timed_animation, _) => Some(linear_timed_animation),
             _ => None,
         }
     }
 
-    pub fn linear_transform_animation(&mut self) -> Option<&mut LinearTransformAnimation> {
+    pub fn linear_transform_animation(&self) -> Option<&LinearTransformAnimation> {
         match self {
             AnimationType::LinearTransform(linear_transform_animation, _) => {
                 Some(linear_transform_animation)
@@ -206,14 +328,14 @@ impl AnimationType {
         }
     }
 
-    pub fn transform_animation(&mut self) -> Option<&mut TransformAnimation> {
+    pub fn transform_animation(&self) -> Option<&TransformAnimation> {
         match self {
             AnimationType::Transform(transform_animation, _) => Some(transform_animation),
             _ => None,
         }
     }
 
-    pub fn single_frame_animation(&mut self) -> Option<&mut SingleFrameAnimation> {
+    pub fn single_frame_animation(&self) -> Option<&SingleFrameAnimation> {
         match self {
             AnimationType::SingleFrame(single_frame_animation, _) => Some(single_frame_animation),
             _ => None,
@@ -231,13 +353,27 @@ impl AnimationType {
         }
     }
 
-    pub fn reset_animation(&mut self) {
+    /// A cursor on the first frame of the animation for an entity to play it with
+    pub fn cursor(&self) -> AnimationCursor {
         match self {
-            AnimationType::Timed(animation, _) => animation.reset_animation(None, None),
-            AnimationType::Transform(animation, _) => animation.reset_animation(None, None),
-            AnimationType::LinearTimed(animation, _) => animation.reset_animation(None),
-            AnimationType::LinearTransform(animation, _) => animation.reset_animation(None),
-            AnimationType::SingleFrame(animation, _) => animation.reset_animation(None, None),
+            AnimationType::Timed(animation, _) => animation.cursor(),
+            AnimationType::Transform(animation, _) => animation.cursor(),
+            AnimationType::LinearTimed(animation, _) => animation.cursor(),
+            AnimationType::LinearTransform(animation, _) => animation.cursor(),
+            AnimationType::SingleFrame(animation, _) => animation.cursor(),
+            AnimationType::None => AnimationCursor::default(),
+        }
+    }
+
+    pub fn reset_animation(&self, cursor: &mut AnimationCursor) {
+        match self {
+            AnimationType::Timed(animation, _) => animation.reset_animation(cursor, None, None),
+            AnimationType::Transform(animation, _) => animation.reset_animation(cursor, None, None),
+            AnimationType::LinearTimed(animation, _) => animation.reset_animation(cursor, None),
+            AnimationType::LinearTransform(animation, _) => animation.reset_animation(cursor, None),
+            AnimationType::SingleFrame(animation, _) => {
+                animation.reset_animation(cursor, None, None)
+            }
             AnimationType::None => panic!("Something went terribly wrong"),
         }
     }
@@ -245,6 +381,158 @@ impl AnimationType {
     pub fn is_none(&self) -> bool {
         matches!(self, AnimationType::None)
     }
+
+    /// Fits the `cursor` of an entity playing `previous` to this animation that replaced it
+    ///
+    /// If both are the same type of animation the current frame and the time it was shown for are kept, otherwise the animation starts over
+    pub fn reload_cursor(&self, previous: &AnimationType, cursor: &mut AnimationCursor) {
+        if std::mem::discriminant(self) != std::mem::discriminant(previous) {
+            *cursor = self.cursor();
+            return;
+        }
+        match (self, previous) {
+            (AnimationType::Timed(animation, _), AnimationType::Timed(previous, _)) => {
+                animation.reload_cursor(previous, cursor)
+            }
+            (AnimationType::Transform(animation, _), AnimationType::Transform(previous, _)) => {
+                animation.reload_cursor(previous, cursor)
+            }
+            (AnimationType::LinearTimed(animation, _), AnimationType::LinearTimed(previous, _)) => {
+                animation.reload_cursor(previous, cursor)
+            }
+            (
+                AnimationType::LinearTransform(animation, _),
+                AnimationType::LinearTransform(previous, _),
+            ) => animation.reload_cursor(previous, cursor),
+            (AnimationType::SingleFrame(animation, _), _) => animation.reload_cursor(cursor),
+            _ => {}
+        }
+    }
+
+    /// Moves `cursor` to the frame at `to` and returns its atlas index
+    ///
+    /// [SingleFrameAnimation]s only have one frame so they stay the same and [None] is returned
+    pub fn seek(
+        &self,
+        cursor: &mut AnimationCursor,
+        to: AnimationSeek,
+        direction: &AnimationDirection,
+    ) -> Option<usize> {
+        match self {
+            AnimationType::Timed(animation, _) => Some(animation.seek(cursor, to, direction)),
+            AnimationType::Transform(animation, _) => Some(animation.seek(cursor, to, direction)),
+            AnimationType::LinearTimed(animation, _) => Some(animation.seek(cursor, to, direction)),
+            AnimationType::LinearTransform(animation, _) => {
+                Some(animation.seek(cursor, to, direction))
+            }
+            AnimationType::SingleFrame(..) | AnimationType::None => None,
+        }
+    }
+
+    /// Moves `cursor` back to the rest frame of the animation and returns its atlas index
+    pub fn stop(
+        &self,
+        cursor: &mut AnimationCursor,
+        direction: &AnimationDirection,
+    ) -> Option<usize> {
+        let rest_frame = match self {
+            AnimationType::Timed(animation, _) => animation.rest_frame,
+            AnimationType::Transform(animation, _) => animation.rest_frame,
+            AnimationType::LinearTimed(animation, _) => animation.rest_frame,
+            AnimationType::LinearTransform(animation, _) => animation.rest_frame,
+            AnimationType::SingleFrame(animation, _) => {
+                animation.reset_animation(cursor, None, None);
+                return Some(animation.sprite_index(cursor, direction));
+            }
+            AnimationType::None => return None,
+        };
+        self.seek(cursor, AnimationSeek::Frame(rest_frame), direction)
+    }
+
+    /// A copy of this animation named `name` that only plays the frames of `slice`
+    ///
+    /// Returns [Result<AnimationType, String>] an [Err(String)] if the frames of `slice` don't exist or the animation is a [SingleFrameAnimation]
+    pub fn slice(&self, name: AnimationName, slice: &AnimationSlice) -> Result<Self, String> {
+        match self {
+            AnimationType::Timed(animation, _) => {
+                Ok(AnimationType::Timed(animation.slice(slice)?, name))
+            }
+            AnimationType::Transform(animation, _) => {
+                Ok(AnimationType::Transform(animation.slice(slice)?, name))
+            }
+            AnimationType::LinearTimed(animation, _) => {
+                Ok(AnimationType::LinearTimed(animation.slice(slice)?, name))
+            }
+            AnimationType::LinearTransform(animation, _) => Ok(AnimationType::LinearTransform(
+                animation.slice(slice)?,
+                name,
+            )),
+            AnimationType::SingleFrame(_, source) => Err(format!(
+                "Animation {source} only has a single frame and can't be sliced"
+            )),
+            AnimationType::None => Err("Can't slice an empty animation".to_string()),
+        }
+    }
+}
+
+/// The part of an existing animation to play as a new animation and which way to play it
+///
+/// # Example
+/// ```rust
+/// // plays frames 2, 3 and 4 of `door_open` backwards
+/// animations.insert_sliced_animation(
+///     "door_close",
+///     "door_open",
+///     AnimationSlice::new().with_frames(2..5).reversed(),
+/// )?;
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AnimationSlice {
+    /// The positions in `animation_frames` to play. Every frame is played if this is [None]
+    pub frames: Option<std::ops::Range<usize>>,
+    /// Plays the frames from last to first
+    pub reverse: bool,
+    /// Replaces the [LoopMode] of the original animation
+    pub loop_mode: Option<LoopMode>,
+}
+
+impl AnimationSlice {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only plays the frames at these positions in `animation_frames`
+    pub fn with_frames(mut self, frames: std::ops::Range<usize>) -> Self {
+        self.frames = Some(frames);
+        self
+    }
+
+    /// Plays the frames from last to first
+    pub fn reversed(mut self) -> Self {
+        self.reverse = true;
+        self
+    }
+
+    pub fn with_loop_mode(mut self, loop_mode: LoopMode) -> Self {
+        self.loop_mode = Some(loop_mode);
+        self
+    }
+
+    /// The positions in `animation_frames` of an animation with `frame_count` frames in the order they're played
+    pub(crate) fn positions(&self, frame_count: usize) -> Result<Vec<usize>, String> {
+        let frames = self.frames.clone().unwrap_or(0..frame_count);
+        if frames.is_empty() || frames.end > frame_count {
+            return Err(format!(
+                "Can't play frames {}..{} of an animation with {} frames",
+                frames.start, frames.end, frame_count
+            ));
+        }
+        let mut positions: Vec<usize> = frames.collect();
+        if self.reverse {
+            positions.reverse();
+        }
+        Ok(positions)
+    }
 }
 
 /// Send a request to animate the `Entity` with the animation dictated by the `AnimationName`
@@ -269,9 +557,84 @@ impl AnimationType {
 /// * **Note** that you can send an event of the same name multiple times even while an animation is in progress without ruining it
 ///
 /// * **Note** an animation that has been sent will animate till end or repeat forever
-#[derive(Debug, Message)]
+#[derive(Debug, Clone, Copy, Message)]
 pub struct AnimationEvent(pub AnimationName, pub Entity);
 
+/// Freezes the animation of an `Entity` on its current frame until a [ResumeAnimation] is sent
+///
+/// # Example
+/// ```rust
+/// fn open_menu(
+///     player_query: Query<Entity, With<Player>>,
+///     mut pause_writer: MessageWriter<PauseAnimation>,
+/// ) {
+///     let player_entity = player_query.single().unwrap();
+///     pause_writer.write(PauseAnimation(player_entity));
+/// }
+/// ```
+#[derive(Debug, Message)]
+pub struct PauseAnimation(pub Entity);
+
+/// Continues the animation of an `Entity` frozen by a [PauseAnimation]
+#[derive(Debug, Message)]
+pub struct ResumeAnimation(pub Entity);
+
+/// Stops the animation of an `Entity` and shows the rest frame of the animation
+///
+/// The animation plays from its rest frame the next time it's started
+#[derive(Debug, Message)]
+pub struct StopAnimation(pub Entity);
+
+/// Moves the animation of an `Entity` to another frame. Also works while the animation is paused
+///
+/// # Example
+/// ```rust
+/// // pose the player halfway through their wave for a cutscene
+/// seek_writer.write(SeekAnimation {
+///     entity: player_entity,
+///     to: AnimationSeek::NormalizedTime(0.5),
+/// });
+/// ```
+#[derive(Debug, Message)]
+pub struct SeekAnimation {
+    pub entity: Entity,
+    pub to: AnimationSeek,
+}
+
+/// Where a [SeekAnimation] moves an animation to
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AnimationSeek {
+    /// The position in `animation_frames`
+    Frame(usize),
+    /// How far through the animation from `0.` to `1.`. Timed animations use their frame timings to pick the frame
+    NormalizedTime(f32),
+}
+
+impl AnimationSeek {
+    /// The position in `animation_frames` to move to for an animation with `frame_count` frames
+    pub(crate) fn position(&self, frame_count: usize, timings: Option<&[f32]>) -> usize {
+        let last = frame_count.saturating_sub(1);
+        match (*self, timings) {
+            (AnimationSeek::Frame(frame), _) => frame.min(last),
+            (AnimationSeek::NormalizedTime(time), Some(timings)) => {
+                let target = time.clamp(0., 1.) * timings.iter().sum::<f32>();
+                let mut elapsed = 0.;
+                timings
+                    .iter()
+                    .position(|timing| {
+                        elapsed += timing;
+                        elapsed > target
+                    })
+                    .unwrap_or(last)
+                    .min(last)
+            }
+            (AnimationSeek::NormalizedTime(time), None) => {
+                ((time.clamp(0., 1.) * frame_count as f32) as usize).min(last)
+            }
+        }
+    }
+}
+
 /// Send a request to reset the animation of an `Entity`
 ///
 /// # Example
@@ -295,6 +658,86 @@ pub struct AnimationEvent(pub AnimationName, pub Entity);
 #[derive(Debug, Message)]
 pub struct ResetAnimationEvent(pub Entity);
 
+/// Sent when a [TimedAnimation] or [LinearTimedAnimation] shows a frame that has a marker
+///
+/// # Example
+/// ```rust
+/// fn play_footsteps(mut frame_events: MessageReader<AnimationFrameEvent>) {
+///     for event in frame_events.read() {
+///         if event.marker == "footstep" {
+///             /* play a footstep sound for event.entity */
+///         }
+///     }
+/// }
+/// ```
+#[derive(Debug, Message, Clone)]
+pub struct AnimationFrameEvent {
+    pub entity: Entity,
+    pub animation: AnimationName,
+    pub marker: &'static str,
+    /// The position of the frame in `animation_frames`
+    pub frame: usize,
+}
+
+/// Sent when an entity starts playing an animation, including when an animation that finished is started again
+///
+/// The animation lifecycle events are sent as messages and also triggered on the entity, so observers on it or any of its parents see them
+///
+/// # Example
+/// ```rust
+/// commands.entity(enemy).observe(|event: On<AnimationStarted>| {
+///     // `event.entity` is the entity the observer is on, the child that started the animation is `event.original_event_target()`
+///     info!("{:?} started {}", event.original_event_target(), event.animation);
+/// });
+/// ```
+#[derive(Debug, Message, EntityEvent, Clone)]
+#[entity_event(propagate, auto_propagate)]
+pub struct AnimationStarted {
+    pub entity: Entity,
+    pub animation: AnimationName,
+}
+
+/// Sent when a looping animation shows its first frame again. Ping pong animations loop once they get back to their first frame
+#[derive(Debug, Message, EntityEvent, Clone)]
+#[entity_event(propagate, auto_propagate)]
+pub struct AnimationLooped {
+    pub entity: Entity,
+    pub animation: AnimationName,
+}
+
+/// Sent when an animation that is still playing is replaced by another one
+#[derive(Debug, Message, EntityEvent, Clone)]
+#[entity_event(propagate, auto_propagate)]
+pub struct AnimationInterrupted {
+    pub entity: Entity,
+    pub animation: AnimationName,
+    /// The animation that replaced it
+    pub by: AnimationName,
+}
+
+/// Sent when an animation runs out of frames. Only animations with a [LoopMode] that ends finish
+///
+/// # Example
+/// ```rust
+/// fn leave_attack(mut finished: MessageReader<AnimationFinished>, mut players: Query<&mut PlayerState>) {
+///     for event in finished.read() {
+///         if event.animation == "player_attack" {
+///             if let Ok(mut state) = players.get_mut(event.entity) {
+///                 *state = PlayerState::Idle;
+///             }
+///         }
+///     }
+/// }
+/// ```
+///
+/// **Note** [SingleFrameAnimation]s never finish
+#[derive(Debug, Message, EntityEvent, Clone)]
+#[entity_event(propagate, auto_propagate)]
+pub struct AnimationFinished {
+    pub entity: Entity,
+    pub animation: AnimationName,
+}
+
 /// Send a request to start an FX animation. This will spawn a new animation for and FX then immediately despawn it
 ///
 /// Needs the `AnimationName` and the position to spawn the new FX animation at
@@ -340,7 +783,34 @@ impl AnimationDirection {
     }
 }
 
+/// Scales how fast the timed animations of an entity play, `2.` plays them twice as fast and `0.5` at half speed
+///
+/// Useful for status effects like haste or slow. [TransformAnimation]s and [LinearTransformAnimation]s follow the entity's movement so they aren't affected
+#[derive(Debug, Component, Clone, Copy, PartialEq, Deref, DerefMut)]
+pub struct AnimationSpeed(pub f32);
+
+impl Default for AnimationSpeed {
+    fn default() -> Self {
+        Self(1.)
+    }
+}
+
+/// Scales how fast every timed animation plays on top of the [AnimationSpeed] of each entity
+///
+/// Set it below `1.` for slow motion and to `0.` to freeze every timed animation
+#[derive(Debug, Resource, Clone, Copy, PartialEq, Deref, DerefMut)]
+pub struct AnimationTimeScale(pub f32);
+
+impl Default for AnimationTimeScale {
+    fn default() -> Self {
+        Self(1.)
+    }
+}
+
+/// Makes an entity able to be animated. The entity gets an [AnimationPlayback] along with it that is removed again with the [Animator]
 #[derive(Debug, PartialEq, Eq, Clone, Default, Component)]
+#[require(AnimationPlayback)]
+#[component(on_remove = remove_animation_playback)]
 pub struct Animator {
     pub direction: AnimationDirection,
 }
@@ -355,3 +825,49 @@ impl Animator {
         &self.direction
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn seeking_a_frame_clamps_to_the_last_one() {
+        assert_eq!(AnimationSeek::Frame(1).position(4, None), 1);
+        assert_eq!(AnimationSeek::Frame(10).position(4, None), 3);
+        assert_eq!(AnimationSeek::Frame(10).position(0, None), 0);
+    }
+
+    #[test]
+    fn seeking_a_normalized_time_clamps_to_the_animation() {
+        assert_eq!(AnimationSeek::NormalizedTime(0.5).position(4, None), 2);
+        assert_eq!(AnimationSeek::NormalizedTime(-1.).position(4, None), 0);
+        assert_eq!(AnimationSeek::NormalizedTime(1.).position(4, None), 3);
+        assert_eq!(AnimationSeek::NormalizedTime(2.).position(4, None), 3);
+    }
+
+    #[test]
+    fn seeking_a_normalized_time_uses_the_frame_timings() {
+        let timings = [0.1, 0.5, 0.2, 0.2];
+        assert_eq!(
+            AnimationSeek::NormalizedTime(0.).position(4, Some(&timings)),
+            0
+        );
+        // 0.3 seconds in is during the long second frame
+        assert_eq!(
+            AnimationSeek::NormalizedTime(0.3).position(4, Some(&timings)),
+            1
+        );
+        assert_eq!(
+            AnimationSeek::NormalizedTime(0.7).position(4, Some(&timings)),
+            2
+        );
+        assert_eq!(
+            AnimationSeek::NormalizedTime(1.).position(4, Some(&timings)),
+            3
+        );
+        assert_eq!(
+            AnimationSeek::NormalizedTime(5.).position(4, Some(&timings)),
+            3
+        );
+    }
+}